@@ -10,6 +10,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
 use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,9 +27,142 @@ pub struct BacktestResult {
     pub win_rate: f64,
     pub max_drawdown: Decimal,
     pub max_drawdown_percent: Decimal,
+    /// Annualized mean return / stddev of per-cycle returns.
+    pub sharpe_ratio: f64,
+    /// Like Sharpe but dividing by downside deviation (negative returns only).
+    pub sortino_ratio: f64,
+    /// Compound annual growth rate implied by the period's total return.
+    pub cagr_percent: f64,
+    /// Annualized return / max drawdown percent.
+    pub calmar_ratio: f64,
+    /// Sum of winning trade profits / absolute sum of losing trade profits.
+    pub profit_factor: f64,
+    /// The bid/ask spread applied to mock fills, so results can be compared
+    /// across execution-cost assumptions.
+    pub effective_spread_percent: Decimal,
+    /// The taker fee rate applied to mock fills.
+    pub effective_fee_percent: Decimal,
+    /// Number of closed baskets per grid level (`Config::trading::grid`), keyed
+    /// by level index. Empty if grid trading wasn't configured.
+    pub grid_level_fills: HashMap<u32, u32>,
     pub config_used: BacktestConfig,
 }
 
+/// Number of backtest cycles per year, assuming hourly cycles (the cadence
+/// `run_backtest` steps through historical data points at).
+const CYCLES_PER_YEAR: f64 = 24.0 * 365.0;
+
+/// Risk-adjusted metrics derived from the per-cycle portfolio value series
+/// already tracked by [`Backtester::run_backtest`], plus profit factor from
+/// the closed baskets. Kept as free functions so they're easy to reuse from
+/// the parameter sweeps in `optimize`.
+fn periodic_returns(portfolio_values: &[Decimal]) -> Vec<f64> {
+    portfolio_values
+        .windows(2)
+        .filter_map(|w| {
+            let (prev, curr) = (w[0], w[1]);
+            if prev > Decimal::ZERO {
+                ((curr - prev) / prev).to_f64()
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
+
+fn stddev(values: &[f64], mean_value: f64) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        (values.iter().map(|v| (v - mean_value).powi(2)).sum::<f64>() / values.len() as f64).sqrt()
+    }
+}
+
+fn downside_deviation(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let squared_downside: f64 = values.iter().map(|v| v.min(0.0).powi(2)).sum();
+    (squared_downside / values.len() as f64).sqrt()
+}
+
+pub fn sharpe_ratio(portfolio_values: &[Decimal]) -> f64 {
+    let returns = periodic_returns(portfolio_values);
+    let mean_return = mean(&returns);
+    let std_return = stddev(&returns, mean_return);
+    if std_return == 0.0 {
+        return 0.0;
+    }
+    mean_return / std_return * CYCLES_PER_YEAR.sqrt()
+}
+
+pub fn sortino_ratio(portfolio_values: &[Decimal]) -> f64 {
+    let returns = periodic_returns(portfolio_values);
+    let mean_return = mean(&returns);
+    let downside = downside_deviation(&returns);
+    if downside == 0.0 {
+        return 0.0;
+    }
+    mean_return / downside * CYCLES_PER_YEAR.sqrt()
+}
+
+/// Annualized return divided by max drawdown, i.e. reward per unit of the
+/// worst peak-to-trough loss actually realized (unlike Sharpe/Sortino, which
+/// penalize volatility generally).
+pub fn calmar_ratio(cagr_percent: f64, max_drawdown_percent: Decimal) -> f64 {
+    let max_drawdown_percent = max_drawdown_percent.to_f64().unwrap_or(0.0);
+    if max_drawdown_percent == 0.0 {
+        return 0.0;
+    }
+    cagr_percent / max_drawdown_percent
+}
+
+pub fn cagr_percent(initial_value: Decimal, final_value: Decimal, period_days: u32) -> f64 {
+    let (Some(initial), Some(final_), true) = (initial_value.to_f64(), final_value.to_f64(), initial_value > Decimal::ZERO)
+    else {
+        return 0.0;
+    };
+    let years = period_days as f64 / 365.0;
+    if years <= 0.0 {
+        return 0.0;
+    }
+    ((final_ / initial).powf(1.0 / years) - 1.0) * 100.0
+}
+
+pub fn profit_factor(closed_baskets: &[crate::state::ClosedBasket]) -> f64 {
+    let (gross_profit, gross_loss) = closed_baskets.iter().fold((0.0, 0.0), |(profit, loss), cb| {
+        match cb.profit.to_f64().unwrap_or(0.0) {
+            p if p > 0.0 => (profit + p, loss),
+            p => (profit, loss - p),
+        }
+    });
+    if gross_loss == 0.0 {
+        if gross_profit > 0.0 { f64::INFINITY } else { 0.0 }
+    } else {
+        gross_profit / gross_loss
+    }
+}
+
+/// Tally closed baskets by `Basket::grid_level`, so a grid backtest shows
+/// which levels actually traded instead of just an aggregate return.
+pub fn grid_level_fill_counts(closed_baskets: &[crate::state::ClosedBasket]) -> HashMap<u32, u32> {
+    let mut counts = HashMap::new();
+    for closed in closed_baskets {
+        if let Some(level) = closed.basket.grid_level {
+            *counts.entry(level).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BacktestConfig {
     pub basket_count: u32,
@@ -36,6 +170,11 @@ pub struct BacktestConfig {
     pub min_investment_percent: Decimal,
     pub max_investment_percent: Decimal,
     pub fear_greed_threshold: u32,
+    /// The exit policy baskets were opened with, recorded here so a result
+    /// can be attributed to the stop-loss/trailing-stop settings that produced it.
+    pub stop_loss_percent: Option<Decimal>,
+    pub trailing_stop_percent: Option<Decimal>,
+    pub trailing_stop_activation_percent: Option<Decimal>,
 }
 
 impl From<&Config> for BacktestConfig {
@@ -46,10 +185,48 @@ impl From<&Config> for BacktestConfig {
             min_investment_percent: config.trading.min_investment_percent,
             max_investment_percent: config.trading.max_investment_percent,
             fear_greed_threshold: config.trading.fear_greed_threshold,
+            stop_loss_percent: config.trading.stop_loss_percent,
+            trailing_stop_percent: config.trading.trailing_stop_percent,
+            trailing_stop_activation_percent: config.trading.trailing_stop_activation_percent,
+        }
+    }
+}
+
+/// Lists of values to try per tunable field in [`Backtester::optimize`]'s
+/// grid sweep; the sweep runs every combination across all five lists.
+#[derive(Debug, Clone)]
+pub struct OptimizeGrid {
+    pub basket_count: Vec<u32>,
+    pub profit_threshold_percent: Vec<Decimal>,
+    pub min_investment_percent: Vec<Decimal>,
+    pub max_investment_percent: Vec<Decimal>,
+    pub fear_greed_threshold: Vec<u32>,
+}
+
+/// Ranking criterion for [`Backtester::optimize`].
+#[derive(Debug, Clone, Copy)]
+pub enum OptimizeObjective {
+    TotalReturnPercent,
+    SharpeRatio,
+}
+
+impl OptimizeObjective {
+    fn score(self, result: &BacktestResult) -> f64 {
+        match self {
+            OptimizeObjective::TotalReturnPercent => result.total_return_percent.to_f64().unwrap_or(f64::NEG_INFINITY),
+            OptimizeObjective::SharpeRatio => result.sharpe_ratio,
         }
     }
 }
 
+/// One combination's result from a [`Backtester::optimize`] sweep.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptimizeGridResult {
+    pub objective_score: f64,
+    pub config_used: BacktestConfig,
+    pub result: BacktestResult,
+}
+
 pub struct Backtester {
     config: Config,
     historical_data: Vec<HistoricalData>,
@@ -134,7 +311,22 @@ impl Backtester {
         initial_balances.insert(self.config.assets.crypto_symbol.clone(), self.config.assets.initial_crypto_amount);
 
         // Create mock client with historical data
-        let mock_client = Arc::new(MockClient::new(self.historical_data.clone(), initial_balances.clone()));
+        let taker_fee_percent = self
+            .config
+            .trading
+            .fee_schedule
+            .map(|f| f.taker_percent)
+            .unwrap_or(Decimal::new(1, 1));
+        let maker_fee_percent = self
+            .config
+            .trading
+            .fee_schedule
+            .map(|f| f.maker_percent)
+            .unwrap_or(Decimal::new(1, 1));
+        let mock_client = Arc::new(
+            MockClient::new(self.historical_data.clone(), initial_balances.clone())
+                .with_execution_costs(self.config.trading.spread_percent, taker_fee_percent, maker_fee_percent),
+        );
         let exchange: Arc<Mutex<dyn ExchangeClient>> = Arc::new(Mutex::new(mock_client.as_ref().clone()));
 
         // Create bot state
@@ -211,6 +403,13 @@ impl Backtester {
 
         let stats = bot.get_state().get_statistics();
 
+        let sharpe = sharpe_ratio(&portfolio_values);
+        let sortino = sortino_ratio(&portfolio_values);
+        let cagr = cagr_percent(initial_portfolio_value, final_portfolio_value, days);
+        let calmar = calmar_ratio(cagr, max_drawdown_percent);
+        let profit_factor_value = profit_factor(&bot.get_state().closed_baskets);
+        let grid_level_fills = grid_level_fill_counts(&bot.get_state().closed_baskets);
+
         let result = BacktestResult {
             period_days: days,
             start_date,
@@ -224,6 +423,14 @@ impl Backtester {
             win_rate: stats.win_rate,
             max_drawdown,
             max_drawdown_percent,
+            sharpe_ratio: sharpe,
+            sortino_ratio: sortino,
+            cagr_percent: cagr,
+            calmar_ratio: calmar,
+            profit_factor: profit_factor_value,
+            effective_spread_percent: self.config.trading.spread_percent,
+            effective_fee_percent: taker_fee_percent,
+            grid_level_fills,
             config_used: BacktestConfig::from(&self.config),
         };
 
@@ -231,18 +438,73 @@ impl Backtester {
         println!("Total return: {} ({:.2}%)", total_return, total_return_percent);
         println!("Max drawdown: {} ({:.2}%)", max_drawdown, max_drawdown_percent);
         println!("Win rate: {:.2}%", stats.win_rate);
+        println!("Sharpe ratio: {:.2}", sharpe);
+        println!("Sortino ratio: {:.2}", sortino);
+        println!("CAGR: {:.2}%", cagr);
+        println!("Calmar ratio: {:.2}", calmar);
+        println!("Profit factor: {:.2}", profit_factor_value);
 
         Ok(result)
     }
 
     fn get_fear_greed_for_timestamp(&self, timestamp: DateTime<Utc>) -> Option<FearGreedIndex> {
-        // Find the closest Fear & Greed index entry
-        self.fear_greed_data
-            .iter()
-            .min_by_key(|fg| (fg.timestamp - timestamp).num_seconds().abs())
-            .cloned()
+        crate::market_data::nearest_by_timestamp(&self.fear_greed_data, timestamp, |fg| fg.timestamp).cloned()
+    }
+
+    /// Sweep every combination in `grid` against the historical/Fear & Greed
+    /// data already loaded by [`Self::load_historical_data`] (loaded once,
+    /// reused for every combination), ranking the results by `objective` so
+    /// users can pick a robust parameter region instead of a single overfit point.
+    pub async fn optimize(
+        &self,
+        days: u32,
+        grid: &OptimizeGrid,
+        objective: OptimizeObjective,
+    ) -> anyhow::Result<Vec<OptimizeGridResult>> {
+        let mut results = Vec::new();
+
+        for &basket_count in &grid.basket_count {
+            for &profit_threshold_percent in &grid.profit_threshold_percent {
+                for &min_investment_percent in &grid.min_investment_percent {
+                    for &max_investment_percent in &grid.max_investment_percent {
+                        for &fear_greed_threshold in &grid.fear_greed_threshold {
+                            let mut config = self.config.clone();
+                            config.trading.basket_count = basket_count;
+                            config.trading.profit_threshold_percent = profit_threshold_percent;
+                            config.trading.min_investment_percent = min_investment_percent;
+                            config.trading.max_investment_percent = max_investment_percent;
+                            config.trading.fear_greed_threshold = fear_greed_threshold;
+
+                            // Reuse the already-loaded data instead of re-reading it from disk per combination.
+                            let combo_backtester = Backtester {
+                                config,
+                                historical_data: self.historical_data.clone(),
+                                fear_greed_data: self.fear_greed_data.clone(),
+                            };
+                            let result = combo_backtester.run_backtest(days).await?;
+                            results.push(OptimizeGridResult {
+                                objective_score: objective.score(&result),
+                                config_used: result.config_used.clone(),
+                                result,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        results.sort_by(|a, b| b.objective_score.partial_cmp(&a.objective_score).unwrap());
+        Ok(results)
     }
 
+    /// Persist a ranked [`Self::optimize`] sweep to `backtest-data`.
+    pub fn save_optimize_results(&self, days: u32, results: &[OptimizeGridResult]) -> anyhow::Result<()> {
+        let filename = format!("backtest-data/optimize_sweep_{days}d.json");
+        let json = serde_json::to_string_pretty(results)?;
+        std::fs::write(&filename, json)?;
+        println!("Parameter sweep results saved to {filename}");
+        Ok(())
+    }
 
     pub fn save_result(&self, result: &BacktestResult) -> anyhow::Result<()> {
         let filename = format!("backtest-data/backtest_result_{}d.json", result.period_days);
@@ -2,6 +2,44 @@ use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+/// Why a basket's [`Basket::check_exit`] decided to close it, so callers can
+/// log and attribute performance by exit type instead of a single bool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExitReason {
+    TakeProfit,
+    StopLoss,
+    TrailingStop,
+}
+
+/// Whether a basket profits from price rising (`Long`, the original and
+/// still-default behavior) or falling (`Short`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Direction {
+    Long,
+    Short,
+}
+
+impl Default for Direction {
+    fn default() -> Self {
+        Direction::Long
+    }
+}
+
+fn default_leverage() -> Decimal {
+    Decimal::ONE
+}
+
+/// Trailing-stop exit: the basket must first gain `activation_percent` before
+/// the stop starts tracking a running peak (long) or trough (short); once
+/// activated, it exits if price retraces `trail_percent` from that peak/trough.
+/// This is stricter than a trailing stop active from the moment of purchase,
+/// which would otherwise trigger on the ordinary noise of an unrealized position.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TrailingStopPolicy {
+    pub trail_percent: Decimal,
+    pub activation_percent: Decimal,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Basket {
     pub id: String,
@@ -10,6 +48,32 @@ pub struct Basket {
     pub buy_price: Decimal,
     pub buy_timestamp: DateTime<Utc>,
     pub target_profit_percent: Decimal,
+    /// Close if price moves this many percent against the basket from
+    /// `buy_price` (below it for a long, above it for a short). `None` disables it.
+    #[serde(default)]
+    pub stop_loss_percent: Option<Decimal>,
+    /// Trailing-stop policy gated behind an activation threshold. `None` disables it.
+    #[serde(default)]
+    pub trailing_stop: Option<TrailingStopPolicy>,
+    #[serde(default)]
+    pub highest_price_since_buy: Decimal,
+    #[serde(default)]
+    pub lowest_price_since_buy: Decimal,
+    /// Running peak (long) or trough (short) tracked only once the trailing
+    /// stop's `activation_percent` has been reached. `None` until activated.
+    #[serde(default)]
+    pub trailing_activation_price: Option<Decimal>,
+    /// Long profits as price rises, short profits as price falls.
+    #[serde(default)]
+    pub direction: Direction,
+    /// Multiplier applied to the raw percentage move; `quantity * buy_price /
+    /// leverage` is the margin actually deducted from `fiat_balance`.
+    #[serde(default = "default_leverage")]
+    pub leverage: Decimal,
+    /// Index of the grid level this basket was opened at, for grid-trading
+    /// baskets. `None` for ordinary dip-buy/ladder/short baskets.
+    #[serde(default)]
+    pub grid_level: Option<u32>,
 }
 
 impl Basket {
@@ -27,27 +91,133 @@ impl Basket {
             buy_price,
             buy_timestamp,
             target_profit_percent,
+            stop_loss_percent: None,
+            trailing_stop: None,
+            highest_price_since_buy: buy_price,
+            lowest_price_since_buy: buy_price,
+            trailing_activation_price: None,
+            direction: Direction::Long,
+            leverage: default_leverage(),
+            grid_level: None,
         }
     }
 
+    pub fn with_stop_loss_percent(mut self, stop_loss_percent: Decimal) -> Self {
+        self.stop_loss_percent = Some(stop_loss_percent);
+        self
+    }
+
+    pub fn with_trailing_stop(mut self, trail_percent: Decimal, activation_percent: Decimal) -> Self {
+        self.trailing_stop = Some(TrailingStopPolicy { trail_percent, activation_percent });
+        self
+    }
+
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn with_leverage(mut self, leverage: Decimal) -> Self {
+        self.leverage = leverage;
+        self
+    }
+
+    pub fn with_grid_level(mut self, grid_level: u32) -> Self {
+        self.grid_level = Some(grid_level);
+        self
+    }
+
+    /// Update the running peak/trough since buy, and the trailing-stop's
+    /// activation state. Call this once per cycle with the current price
+    /// before evaluating [`Self::check_exit`].
+    pub fn update_highest_price(&mut self, current_price: Decimal) {
+        if current_price > self.highest_price_since_buy {
+            self.highest_price_since_buy = current_price;
+        }
+        if current_price < self.lowest_price_since_buy {
+            self.lowest_price_since_buy = current_price;
+        }
+
+        if let Some(policy) = self.trailing_stop {
+            match self.trailing_activation_price {
+                None => {
+                    if self.get_profit_percent(current_price) >= policy.activation_percent {
+                        self.trailing_activation_price = Some(current_price);
+                    }
+                }
+                Some(peak) => {
+                    let tracked = match self.direction {
+                        Direction::Long => current_price.max(peak),
+                        Direction::Short => current_price.min(peak),
+                    };
+                    self.trailing_activation_price = Some(tracked);
+                }
+            }
+        }
+    }
+
+    /// Decide whether this basket should close, and why. Take-profit is
+    /// checked first, then stop-loss, then the trailing stop.
+    pub fn check_exit(&self, current_price: Decimal) -> Option<ExitReason> {
+        if self.should_sell(current_price) {
+            return Some(ExitReason::TakeProfit);
+        }
+
+        if let Some(stop_loss_percent) = self.stop_loss_percent {
+            let adverse_percent = match self.direction {
+                Direction::Long => (self.buy_price - current_price) / self.buy_price * Decimal::from(100),
+                Direction::Short => (current_price - self.buy_price) / self.buy_price * Decimal::from(100),
+            };
+            if adverse_percent >= stop_loss_percent {
+                return Some(ExitReason::StopLoss);
+            }
+        }
+
+        if let (Some(policy), Some(peak)) = (self.trailing_stop, self.trailing_activation_price) {
+            let triggered = match self.direction {
+                Direction::Long => {
+                    let floor = peak * (Decimal::from(100) - policy.trail_percent) / Decimal::from(100);
+                    current_price <= floor
+                }
+                Direction::Short => {
+                    let ceiling = peak * (Decimal::from(100) + policy.trail_percent) / Decimal::from(100);
+                    current_price >= ceiling
+                }
+            };
+            if triggered {
+                return Some(ExitReason::TrailingStop);
+            }
+        }
+
+        None
+    }
+
     pub fn should_sell(&self, current_price: Decimal) -> bool {
-        let profit_percent = (current_price - self.buy_price) / self.buy_price * Decimal::from(100);
-        profit_percent >= self.target_profit_percent
+        self.get_profit_percent(current_price) >= self.target_profit_percent
     }
 
+    /// Current mark-to-market value: the margin committed plus the
+    /// (leveraged) profit or loss accrued since `buy_price`.
     pub fn get_current_value(&self, current_price: Decimal) -> Decimal {
-        self.quantity * current_price
+        self.get_invested_amount() + self.get_profit(current_price)
     }
 
+    /// Margin actually deducted from `fiat_balance` when the basket was opened.
     pub fn get_invested_amount(&self) -> Decimal {
-        self.quantity * self.buy_price
+        self.quantity * self.buy_price / self.leverage
     }
 
     pub fn get_profit(&self, current_price: Decimal) -> Decimal {
-        self.get_current_value(current_price) - self.get_invested_amount()
+        self.get_invested_amount() * self.get_profit_percent(current_price) / Decimal::from(100)
     }
 
+    /// Leveraged percentage move in the basket's favor: positive for a long
+    /// when price rises, positive for a short when price falls.
     pub fn get_profit_percent(&self, current_price: Decimal) -> Decimal {
-        (current_price - self.buy_price) / self.buy_price * Decimal::from(100)
+        let raw_percent = match self.direction {
+            Direction::Long => (current_price - self.buy_price) / self.buy_price * Decimal::from(100),
+            Direction::Short => (self.buy_price - current_price) / self.buy_price * Decimal::from(100),
+        };
+        raw_percent * self.leverage
     }
 }
@@ -1,11 +1,13 @@
 use crate::{
-    config::Config,
-    exchange::{ExchangeClient, Price},
-    basket::Basket,
-    state::BotState,
+    config::{Config, LadderConfig},
+    exchange::{ExchangeClient, OrderSide, Price},
+    basket::{Basket, Direction, ExitReason},
+    state::{BotState, PendingRung},
     fear_greed::{FearGreedClient, FearGreedIndex},
+    notify::{Notifier, TradeEvent},
 };
 use chrono::{DateTime, Utc};
+use futures_util::StreamExt;
 use rust_decimal::Decimal;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -16,6 +18,7 @@ pub struct TradingBot {
     exchange: Arc<Mutex<dyn ExchangeClient>>,
     fear_greed_client: FearGreedClient,
     state: BotState,
+    notifiers: Vec<Box<dyn Notifier>>,
 }
 
 impl TradingBot {
@@ -24,11 +27,23 @@ impl TradingBot {
         exchange: Arc<Mutex<dyn ExchangeClient>>,
         state: BotState,
     ) -> Self {
+        let notifiers = crate::notify::build_notifiers(&config.notify);
         Self {
             config,
             exchange,
             fear_greed_client: FearGreedClient::new(),
             state,
+            notifiers,
+        }
+    }
+
+    /// Push `event` to every configured notifier. A failing webhook is
+    /// logged and otherwise ignored so one bad channel can't abort a cycle.
+    async fn notify(&self, event: TradeEvent) {
+        for notifier in &self.notifiers {
+            if let Err(e) = notifier.send(&event).await {
+                eprintln!("Notifier failed to send event: {e}");
+            }
         }
     }
 
@@ -47,47 +62,136 @@ impl TradingBot {
         let current_prices = self.get_current_prices().await?;
         let price_map = self.prices_to_map(&current_prices);
 
-        // Update recent highs for price tracking
+        // Update recent highs/lows for price tracking
         for (symbol, &price) in &price_map {
             self.state.update_recent_high(symbol, price);
+            self.state.update_recent_low(symbol, price);
         }
 
         // Get Fear & Greed index (use override if provided, otherwise try API)
-        let fear_greed_index = if let Some(override_index) = fear_greed_override {
-            override_index
-        } else {
-            match self.fear_greed_client.get_current_index().await {
-                Ok(index) => index,
-                Err(_) => {
-                    // Fallback for testing/backtesting when API is not available
-                    crate::fear_greed::FearGreedIndex {
-                        value: 35, // Default fear value
-                        classification: "Fear".to_string(),
-                        timestamp: chrono::Utc::now(),
-                    }
-                }
-            }
+        let fear_greed_index = match fear_greed_override {
+            Some(override_index) => override_index,
+            None => self.fetch_fear_greed_index().await,
         };
         println!("Fear & Greed Index: {} ({})", fear_greed_index.value, fear_greed_index.classification);
 
         // Check for sell opportunities
         self.check_sell_opportunities(&price_map).await?;
 
+        // Fill any ladder rungs whose price level has been reached
+        self.check_ladder_fills(&price_map, simulation_time).await?;
+
         // Check for buy opportunities
         self.check_buy_opportunities(&fear_greed_index, &price_map, simulation_time).await?;
 
+        // Check for short opportunities (sell into greed / short the rip)
+        self.check_short_opportunities(&fear_greed_index, &price_map, simulation_time).await?;
+
+        // Grid trading: buy/sell fixed price levels independent of the dip-buying engine above
+        self.check_grid_opportunities(&price_map, simulation_time).await?;
+
+        // Steer held balances toward configured target weights, if configured
+        self.check_portfolio_rebalance(&price_map).await?;
+
         // Save state
         self.state.save_to_file(&self.config.state_file)?;
 
         println!("Trading cycle completed");
         self.print_statistics(&price_map, simulation_time);
+        self.notify_cycle_statistics(&price_map).await;
 
         Ok(())
     }
 
+    async fn notify_cycle_statistics(&self, current_prices: &HashMap<String, Decimal>) {
+        let stats = self.state.get_statistics();
+        let portfolio_value = self.state.get_total_portfolio_value(current_prices);
+        self.notify(TradeEvent::CycleStatistics {
+            portfolio_value,
+            active_baskets: stats.active_baskets_count,
+            total_trades: stats.total_trades,
+            win_rate: stats.win_rate,
+        })
+        .await;
+    }
+
+    /// Run an indefinite loop reacting to live ticks from the exchange's
+    /// WebSocket feed instead of polling once per `run_cycle`. Every tick
+    /// updates the recent-high tracker and re-evaluates sell/buy conditions,
+    /// so fast dips are caught as they happen rather than on the next cycle.
+    pub async fn run_stream(&mut self) -> anyhow::Result<()> {
+        let mut symbols = vec![self.config.assets.crypto_symbol.clone()];
+        if let Some(portfolio) = &self.config.assets.portfolio {
+            for target in &portfolio.targets {
+                if !symbols.contains(&target.symbol) {
+                    symbols.push(target.symbol.clone());
+                }
+            }
+        }
+        let mut stream = {
+            let exchange = self.exchange.lock().await;
+            exchange.subscribe_prices(&symbols).await?
+        };
+
+        let mut fear_greed_index = self.fetch_fear_greed_index().await;
+        let mut ticks_since_refresh = 0u32;
+        const FEAR_GREED_REFRESH_TICKS: u32 = 60;
+
+        // Rebalancing needs every target symbol's price at once, unlike the
+        // single-asset checks below, so track the latest tick per symbol
+        // instead of re-deriving a fresh one-entry map each time.
+        let mut last_known_prices = HashMap::new();
+
+        while let Some(price) = stream.next().await {
+            self.state.update_recent_high(&price.symbol, price.price);
+            self.state.update_recent_low(&price.symbol, price.price);
+            last_known_prices.insert(price.symbol.clone(), price.price);
+
+            if ticks_since_refresh >= FEAR_GREED_REFRESH_TICKS {
+                fear_greed_index = self.fetch_fear_greed_index().await;
+                ticks_since_refresh = 0;
+            }
+            ticks_since_refresh += 1;
+
+            let mut price_map = HashMap::new();
+            price_map.insert(price.symbol.clone(), price.price);
+
+            self.check_sell_opportunities(&price_map).await?;
+            self.check_ladder_fills(&price_map, None).await?;
+            self.check_buy_opportunities(&fear_greed_index, &price_map, None).await?;
+            self.check_short_opportunities(&fear_greed_index, &price_map, None).await?;
+            self.check_grid_opportunities(&price_map, None).await?;
+            self.check_portfolio_rebalance(&last_known_prices).await?;
+
+            self.state.save_to_file(&self.config.state_file)?;
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_fear_greed_index(&self) -> FearGreedIndex {
+        match self.fear_greed_client.get_current_index().await {
+            Ok(index) => index,
+            Err(_) => FearGreedIndex {
+                value: 35,
+                classification: "Fear".to_string(),
+                timestamp: chrono::Utc::now(),
+            },
+        }
+    }
+
     async fn get_current_prices(&self) -> anyhow::Result<Vec<Price>> {
+        let mut symbols = vec![self.config.assets.crypto_symbol.clone()];
+        if let Some(portfolio) = &self.config.assets.portfolio {
+            for target in &portfolio.targets {
+                if !symbols.contains(&target.symbol) {
+                    symbols.push(target.symbol.clone());
+                }
+            }
+        }
+
         let exchange = self.exchange.lock().await;
-        exchange.get_prices(&[self.config.assets.crypto_symbol.clone()]).await
+        exchange.get_prices(&symbols).await
     }
 
     fn prices_to_map(&self, prices: &[Price]) -> HashMap<String, Decimal> {
@@ -99,32 +203,156 @@ impl TradingBot {
     async fn check_sell_opportunities(&mut self, current_prices: &HashMap<String, Decimal>) -> anyhow::Result<()> {
         let mut baskets_to_close = Vec::new();
 
-        for basket in &self.state.active_baskets {
+        for basket in &mut self.state.active_baskets {
             if let Some(&current_price) = current_prices.get(&basket.asset) {
-                if basket.should_sell(current_price) {
+                basket.update_highest_price(current_price);
+
+                if let Some(exit_reason) = basket.check_exit(current_price) {
                     println!(
-                        "Selling basket {} for {} at price {} (bought at {})",
-                        basket.id, basket.asset, current_price, basket.buy_price
+                        "Selling basket {} for {} at price {} (bought at {}, reason: {:?})",
+                        basket.id, basket.asset, current_price, basket.buy_price, exit_reason
                     );
 
-                    // Execute sell order
+                    // Close the position: a long sells its crypto, a short buys it back
                     let exchange = self.exchange.lock().await;
-                    let order_result = exchange.sell(&basket.asset, basket.quantity).await?;
-                    
-                    println!("Sell order executed: {:?}", order_result);
-                    baskets_to_close.push((basket.id.clone(), current_price));
+                    let order_result = match basket.direction {
+                        Direction::Long => exchange.sell(&basket.asset, basket.quantity).await?,
+                        Direction::Short => exchange.close_position(&basket.asset, OrderSide::Buy, basket.quantity).await?,
+                    };
+                    drop(exchange);
+
+                    println!("Close order executed: {:?}", order_result);
+                    baskets_to_close.push((
+                        basket.id.clone(),
+                        basket.asset.clone(),
+                        basket.quantity,
+                        current_price,
+                        exit_reason,
+                        basket.get_profit(current_price),
+                        basket.get_profit_percent(current_price),
+                        order_result.fee,
+                    ));
                 }
             }
         }
 
         // Close sold baskets
-        for (basket_id, sell_price) in baskets_to_close {
-            self.state.close_basket(&basket_id, sell_price)?;
+        for (basket_id, symbol, quantity, sell_price, exit_reason, profit, profit_percent, fee) in baskets_to_close {
+            self.state.close_basket(&basket_id, sell_price, exit_reason, fee)?;
+            self.notify(TradeEvent::SellFilled {
+                symbol,
+                quantity,
+                price: sell_price,
+                profit,
+                profit_percent,
+            })
+            .await;
+        }
+
+        Ok(())
+    }
+
+    /// Turn any pending ladder rungs that `current_prices` has reached into
+    /// active baskets, buying with the capital already reserved for them.
+    async fn check_ladder_fills(
+        &mut self,
+        current_prices: &HashMap<String, Decimal>,
+        simulation_time: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<()> {
+        let crypto_symbol = self.config.assets.crypto_symbol.clone();
+        let Some(&current_price) = current_prices.get(&crypto_symbol) else {
+            return Ok(());
+        };
+
+        let triggered = self.state.take_triggered_rungs(&crypto_symbol, current_price);
+        for rung in triggered {
+            println!(
+                "Ladder rung {} filling for {} at price {} (level {})",
+                rung.id, rung.asset, current_price, rung.buy_price_level
+            );
+
+            let exchange = self.exchange.lock().await;
+            let order_result = exchange.buy(&rung.asset, rung.allocated_fiat).await?;
+            drop(exchange);
+
+            println!("Ladder buy order executed: {:?}", order_result);
+
+            let current_time = simulation_time.unwrap_or_else(chrono::Utc::now);
+            let basket = Basket::new_with_time(
+                rung.asset.clone(),
+                order_result.quantity,
+                order_result.price,
+                rung.target_profit_percent,
+                current_time,
+            );
+            self.state.fill_rung(self.apply_exit_policy(basket));
+            self.state.fiat_balance -= order_result.fee;
+            self.notify(TradeEvent::BuyFilled {
+                symbol: rung.asset,
+                quantity: order_result.quantity,
+                price: order_result.price,
+            })
+            .await;
         }
 
         Ok(())
     }
 
+    /// Split a dip-signal buy into `ladder.rung_count` rungs linearly spaced
+    /// between `current_price` and `current_price * (1 - max_drop_percent / 100)`,
+    /// dollar-cost-averaging into the dip instead of buying a single lump sum.
+    async fn place_ladder(
+        &mut self,
+        ladder: &LadderConfig,
+        dip_percent: Decimal,
+        current_price: Decimal,
+    ) -> anyhow::Result<()> {
+        let crypto_symbol = self.config.assets.crypto_symbol.clone();
+        let investment_percent = self.calculate_dip_investment_percent(dip_percent);
+        let total_investment = self.state.fiat_balance * investment_percent / Decimal::from(100);
+
+        if total_investment <= Decimal::ZERO || ladder.rung_count == 0 {
+            println!("No available capital for ladder placement");
+            return Ok(());
+        }
+
+        let rung_count = ladder.rung_count;
+        let lower_bound = current_price * (Decimal::from(100) - ladder.max_drop_percent) / Decimal::from(100);
+        let span = current_price - lower_bound;
+
+        // Weight progressively more capital toward lower rungs (1, 2, ..., N)
+        // when configured, otherwise split the total evenly.
+        let weights: Vec<Decimal> = if ladder.weight_lower_rungs {
+            (1..=rung_count).map(Decimal::from).collect()
+        } else {
+            (1..=rung_count).map(|_| Decimal::ONE).collect()
+        };
+        let weight_sum: Decimal = weights.iter().sum();
+
+        let mut rungs = Vec::with_capacity(rung_count as usize);
+        for (i, weight) in weights.into_iter().enumerate() {
+            let rung_index = Decimal::from(i as u32 + 1);
+            let buy_price_level = current_price - span * rung_index / Decimal::from(rung_count);
+            let allocated_fiat = total_investment * weight / weight_sum;
+
+            println!(
+                "Placing ladder rung {}/{} for {} at level {} with {} reserved",
+                i + 1, rung_count, crypto_symbol, buy_price_level, allocated_fiat
+            );
+
+            rungs.push(PendingRung {
+                id: format!("rung_{}_{}_{}", crypto_symbol, chrono::Utc::now().timestamp(), i),
+                asset: crypto_symbol.clone(),
+                buy_price_level,
+                allocated_fiat,
+                target_profit_percent: self.config.trading.profit_threshold_percent,
+            });
+        }
+
+        self.state.add_pending_rungs(rungs);
+        Ok(())
+    }
+
     async fn check_buy_opportunities(
         &mut self,
         fear_greed_index: &FearGreedIndex,
@@ -154,12 +382,38 @@ impl TradingBot {
         }
 
         if fear_greed_signal {
-            println!("Fear & Greed buy signal triggered: {} <= {}", 
+            println!("Fear & Greed buy signal triggered: {} <= {}",
                 fear_greed_index.value, self.config.trading.fear_greed_threshold);
+            self.notify(TradeEvent::SignalTriggered {
+                signal: "Fear & Greed".to_string(),
+                detail: format!("{} <= {}", fear_greed_index.value, self.config.trading.fear_greed_threshold),
+            })
+            .await;
         }
         if dip_signal {
-            println!("Buy the dip signal triggered: price dropped {:.2}% from recent high (threshold: {}%)", 
+            println!("Buy the dip signal triggered: price dropped {:.2}% from recent high (threshold: {}%)",
                 dip_percent, self.config.trading.buy_the_dip_percent);
+            self.notify(TradeEvent::SignalTriggered {
+                signal: "Buy the dip".to_string(),
+                detail: format!("{:.2}% drop (threshold: {}%)", dip_percent, self.config.trading.buy_the_dip_percent),
+            })
+            .await;
+        }
+
+        // A configured ladder takes over dip entries entirely: instead of one
+        // lump-sum basket, it reserves capital across several rungs and lets
+        // check_ladder_fills turn each rung into a basket as price reaches it.
+        if dip_signal {
+            if let Some(ladder) = self.config.trading.ladder.clone() {
+                if let Some(&current_price) = current_prices.get(crypto_symbol) {
+                    if self.state.pending_rungs.iter().any(|r| r.asset == *crypto_symbol) {
+                        println!("Ladder already placed for {crypto_symbol}, waiting for rungs to fill");
+                    } else {
+                        self.place_ladder(&ladder, dip_percent, current_price).await?;
+                    }
+                    return Ok(());
+                }
+            }
         }
 
         // Check if we have available basket slots
@@ -207,12 +461,264 @@ impl TradingBot {
                 current_time,
             );
 
-            self.state.add_basket(basket);
+            self.state.add_basket(self.apply_exit_policy(basket));
+            self.state.fiat_balance -= order_result.fee;
+            self.notify(TradeEvent::BuyFilled {
+                symbol: crypto_symbol.clone(),
+                quantity: order_result.quantity,
+                price: order_result.price,
+            })
+            .await;
+        }
+
+        Ok(())
+    }
+
+    /// Symmetrical counterpart to [`Self::check_buy_opportunities`]: opens a
+    /// short basket when the Fear & Greed index swings into greed, or price
+    /// has rallied sharply above its recent low. Disabled unless `greed_threshold`
+    /// or `sell_into_greed_percent` is configured.
+    async fn check_short_opportunities(
+        &mut self,
+        fear_greed_index: &FearGreedIndex,
+        current_prices: &HashMap<String, Decimal>,
+        simulation_time: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<()> {
+        let crypto_symbol = self.config.assets.crypto_symbol.clone();
+        let Some(&current_price) = current_prices.get(&crypto_symbol) else {
+            return Ok(());
+        };
+
+        let greed_signal = self
+            .config
+            .trading
+            .greed_threshold
+            .is_some_and(|threshold| fear_greed_index.value >= threshold);
+        let rally_signal = self.config.trading.sell_into_greed_percent.is_some_and(|threshold| {
+            self.state.is_price_rally(&crypto_symbol, current_price, threshold)
+        });
+
+        if !greed_signal && !rally_signal {
+            return Ok(());
+        }
+
+        if self.state.active_baskets.len() >= self.config.trading.basket_count as usize {
+            println!("All basket slots are occupied, skipping short entry");
+            return Ok(());
+        }
+
+        if greed_signal {
+            println!("Greed buy signal triggered for short entry: {} >= {:?}",
+                fear_greed_index.value, self.config.trading.greed_threshold);
+            self.notify(TradeEvent::SignalTriggered {
+                signal: "Sell into greed".to_string(),
+                detail: format!("{} >= {:?}", fear_greed_index.value, self.config.trading.greed_threshold),
+            })
+            .await;
+        }
+        if rally_signal {
+            let rally_percent = self.state.get_rally_percentage(&crypto_symbol, current_price);
+            println!("Short the rip signal triggered: price rallied {rally_percent:.2}% from recent low");
+            self.notify(TradeEvent::SignalTriggered {
+                signal: "Short the rip".to_string(),
+                detail: format!("{rally_percent:.2}% rally from recent low"),
+            })
+            .await;
+        }
+
+        let investment_percent = self.calculate_investment_percent(100 - fear_greed_index.value);
+        let margin = self.state.fiat_balance * investment_percent / Decimal::from(100);
+        if margin <= Decimal::ZERO {
+            println!("No available capital for short basket");
+            return Ok(());
+        }
+
+        let leverage = self.config.trading.short_leverage.unwrap_or(Decimal::ONE);
+
+        println!("Opening short basket for {crypto_symbol} with margin {margin} at {leverage}x leverage");
+        let exchange = self.exchange.lock().await;
+        let order_result = exchange.open_position(&crypto_symbol, OrderSide::Sell, margin, leverage).await?;
+        drop(exchange);
+
+        println!("Short open order executed: {:?}", order_result);
+
+        let current_time = simulation_time.unwrap_or_else(|| chrono::Utc::now());
+        let basket = Basket::new_with_time(
+            crypto_symbol.clone(),
+            order_result.quantity,
+            order_result.price,
+            self.config.trading.profit_threshold_percent,
+            current_time,
+        )
+        .with_direction(Direction::Short)
+        .with_leverage(leverage);
+
+        self.state.add_basket(self.apply_exit_policy(basket));
+        self.state.fiat_balance -= order_result.fee;
+        self.notify(TradeEvent::BuyFilled {
+            symbol: crypto_symbol,
+            quantity: order_result.quantity,
+            price: order_result.price,
+        })
+        .await;
+
+        Ok(())
+    }
+
+    /// Grid trading, independent of and alongside the dip-buying/ladder/short
+    /// engines above: buys a level's worth of capital when price crosses down
+    /// through one of `Config::trading::grid`'s evenly-spaced levels, and lets
+    /// the basket's own take-profit target (set to the next level up) close it
+    /// when price rallies back through that level. Disabled unless `grid` is configured.
+    async fn check_grid_opportunities(
+        &mut self,
+        current_prices: &HashMap<String, Decimal>,
+        simulation_time: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<()> {
+        let Some(grid) = self.config.trading.grid.clone() else {
+            return Ok(());
+        };
+        if grid.grid_count < 2 {
+            println!("Grid requires at least 2 levels, skipping");
+            return Ok(());
+        }
+
+        let crypto_symbol = self.config.assets.crypto_symbol.clone();
+        let Some(&current_price) = current_prices.get(&crypto_symbol) else {
+            return Ok(());
+        };
+
+        let lower = grid.lower.or_else(|| self.state.recent_lows.get(&crypto_symbol).copied());
+        let upper = grid.upper.or_else(|| self.state.recent_highs.get(&crypto_symbol).copied());
+        let (Some(lower), Some(upper)) = (lower, upper) else {
+            println!("Grid has no price band yet (no lower/upper configured or tracked), skipping");
+            return Ok(());
+        };
+        if upper <= lower {
+            return Ok(());
+        }
+
+        let levels = grid_levels(lower, upper, grid.grid_count);
+        let capital_per_level = self.state.fiat_balance / Decimal::from(grid.grid_count);
+        if capital_per_level <= Decimal::ZERO {
+            return Ok(());
+        }
+
+        for (level_index, window) in levels.windows(2).enumerate() {
+            let (buy_level, sell_level) = (window[0], window[1]);
+            let level_index = level_index as u32;
+
+            if current_price > buy_level {
+                continue;
+            }
+            if self
+                .state
+                .active_baskets
+                .iter()
+                .any(|b| b.asset == crypto_symbol && b.grid_level == Some(level_index))
+            {
+                continue;
+            }
+
+            let target_profit_percent = (sell_level - buy_level) / buy_level * Decimal::from(100);
+
+            println!(
+                "Grid level {} crossed for {} at price {} (level {}); opening basket targeting {}",
+                level_index, crypto_symbol, current_price, buy_level, sell_level
+            );
+
+            let exchange = self.exchange.lock().await;
+            let order_result = exchange.buy(&crypto_symbol, capital_per_level).await?;
+            drop(exchange);
+
+            let current_time = simulation_time.unwrap_or_else(chrono::Utc::now);
+            let basket = Basket::new_with_time(
+                crypto_symbol.clone(),
+                order_result.quantity,
+                order_result.price,
+                target_profit_percent,
+                current_time,
+            )
+            .with_grid_level(level_index);
+
+            self.state.add_basket(self.apply_exit_policy(basket));
+            self.state.fiat_balance -= order_result.fee;
+            self.notify(TradeEvent::BuyFilled {
+                symbol: crypto_symbol.clone(),
+                quantity: order_result.quantity,
+                price: order_result.price,
+            })
+            .await;
         }
 
         Ok(())
     }
 
+    /// Steer `crypto_balances` toward `Config::assets::portfolio`'s target
+    /// weights, if configured, no more often than `rebalance_every_cycles`.
+    async fn check_portfolio_rebalance(&mut self, current_prices: &HashMap<String, Decimal>) -> anyhow::Result<()> {
+        let Some(portfolio) = self.config.assets.portfolio.clone() else {
+            return Ok(());
+        };
+
+        self.state.cycles_since_rebalance += 1;
+        if self.state.cycles_since_rebalance < portfolio.rebalance_every_cycles {
+            return Ok(());
+        }
+        self.state.cycles_since_rebalance = 0;
+
+        let trades = self.state.rebalance(
+            current_prices,
+            &portfolio.targets,
+            portfolio.min_cash_reserve,
+            portfolio.min_trade_volume,
+        );
+
+        for trade in trades {
+            let Some(&price) = current_prices.get(&trade.symbol) else {
+                continue;
+            };
+
+            let exchange = self.exchange.lock().await;
+            let order_result = match trade.side {
+                OrderSide::Buy => exchange.buy(&trade.symbol, trade.value).await?,
+                OrderSide::Sell => exchange.sell(&trade.symbol, trade.value / price).await?,
+            };
+            drop(exchange);
+
+            println!(
+                "Rebalance: {:?} {} {} at {}",
+                trade.side, order_result.quantity, trade.symbol, order_result.price
+            );
+            self.state.apply_rebalance_fill(
+                &trade.symbol,
+                trade.side,
+                order_result.quantity,
+                order_result.price,
+                order_result.fee,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Attach the configured stop-loss/trailing-stop percents to a freshly
+    /// bought basket, if any are set.
+    fn apply_exit_policy(&self, mut basket: Basket) -> Basket {
+        if let Some(stop_loss_percent) = self.config.trading.stop_loss_percent {
+            basket = basket.with_stop_loss_percent(stop_loss_percent);
+        }
+        if let Some(trailing_stop_percent) = self.config.trading.trailing_stop_percent {
+            let activation_percent = self
+                .config
+                .trading
+                .trailing_stop_activation_percent
+                .unwrap_or(Decimal::ZERO);
+            basket = basket.with_trailing_stop(trailing_stop_percent, activation_percent);
+        }
+        basket
+    }
+
     fn calculate_investment_percent(&self, fear_greed_value: u32) -> Decimal {
         // Lower fear & greed index = higher investment
         // Scale between min and max investment percentages
@@ -297,4 +803,12 @@ impl TradingBot {
     pub fn get_state_mut(&mut self) -> &mut BotState {
         &mut self.state
     }
+}
+
+/// `count` linearly-spaced price levels from `lower` to `upper`, inclusive.
+fn grid_levels(lower: Decimal, upper: Decimal, count: u32) -> Vec<Decimal> {
+    let span = upper - lower;
+    (0..count)
+        .map(|i| lower + span * Decimal::from(i) / Decimal::from(count - 1))
+        .collect()
 }
\ No newline at end of file
@@ -8,6 +8,59 @@ pub struct Config {
     pub assets: AssetConfig,
     pub state_file: String,
     pub mode: TradingMode,
+    #[serde(default)]
+    pub notify: Option<NotifyConfig>,
+    #[serde(default)]
+    pub market_data: Option<MarketDataConfig>,
+}
+
+/// Selects and configures a [`crate::market_data::MarketDataProvider`] for the
+/// `fetch-data` command to populate `backtest-data` with, instead of hand-crafting files.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MarketDataConfig {
+    pub provider: MarketDataProviderKind,
+    /// Required by the `coinmarketcap`/`alphavantage` providers.
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Required by the `http` provider; `{symbol}`/`{days}` placeholders are substituted in.
+    #[serde(default)]
+    pub url_template: Option<String>,
+    /// Seconds a cached fetch stays valid before a re-fetch is triggered.
+    #[serde(default = "default_cache_expire_seconds")]
+    pub cache_expire_seconds: i64,
+}
+
+fn default_cache_expire_seconds() -> i64 {
+    86_400
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarketDataProviderKind {
+    Http,
+    CoinMarketCap,
+    AlphaVantage,
+}
+
+/// Webhook-backed notification channels events are fanned out to. Any
+/// combination may be configured simultaneously.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NotifyConfig {
+    #[serde(default)]
+    pub telegram: Option<TelegramConfig>,
+    #[serde(default)]
+    pub discord: Option<DiscordConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TelegramConfig {
+    pub bot_token: String,
+    pub chat_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DiscordConfig {
+    pub webhook_url: String,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -27,6 +80,84 @@ pub struct TradingConfig {
     pub max_investment_percent: Decimal,
     pub fear_greed_threshold: u32,
     pub buy_the_dip_percent: Decimal,
+    #[serde(default)]
+    pub ladder: Option<LadderConfig>,
+    /// Grid/ladder trading across a fixed price band, independent of the dip-buying engine above.
+    #[serde(default)]
+    pub grid: Option<GridConfig>,
+    /// Close a basket if price moves this many percent against it from its
+    /// buy price (below it for a long, above it for a short).
+    #[serde(default)]
+    pub stop_loss_percent: Option<Decimal>,
+    /// Close a basket if price retraces this many percent from its peak (long)
+    /// or trough (short), tracked from the moment the trailing stop activates.
+    #[serde(default)]
+    pub trailing_stop_percent: Option<Decimal>,
+    /// Percent the basket must gain before the trailing stop starts tracking
+    /// a peak/trough; `None` (or 0) activates it as soon as the basket is at
+    /// or above break-even.
+    #[serde(default)]
+    pub trailing_stop_activation_percent: Option<Decimal>,
+    /// Open a short basket when the Fear & Greed index is at or above this value.
+    #[serde(default)]
+    pub greed_threshold: Option<u32>,
+    /// Open a short basket when price has rallied this many percent above its recent low.
+    #[serde(default)]
+    pub sell_into_greed_percent: Option<Decimal>,
+    /// Leverage applied to short baskets opened via `greed_threshold`/`sell_into_greed_percent`.
+    #[serde(default)]
+    pub short_leverage: Option<Decimal>,
+    /// Bid/ask spread applied to mock fills, e.g. 0.2 = 0.2%. Buys fill at
+    /// `mid_price * (1 + spread/200)`, sells at `mid_price * (1 - spread/200)`.
+    #[serde(default = "default_spread_percent")]
+    pub spread_percent: Decimal,
+    /// Maker/taker fee rates applied to mock fills. Defaults to the crate's
+    /// original flat 0.1% if unset.
+    #[serde(default)]
+    pub fee_schedule: Option<FeeSchedule>,
+}
+
+fn default_spread_percent() -> Decimal {
+    Decimal::new(2, 1) // 0.2%
+}
+
+/// Execution cost model applied by [`crate::exchange::mock::MockClient`]
+/// fills, so backtests reflect realistic transaction costs instead of the
+/// historical price exactly.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct FeeSchedule {
+    pub maker_percent: Decimal,
+    pub taker_percent: Decimal,
+}
+
+/// Splits a dip-signal buy into several rungs placed at linearly-spaced
+/// price levels below the current price, instead of one lump-sum basket.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LadderConfig {
+    /// Number of rungs to place between the current price and the lower bound.
+    pub rung_count: u32,
+    /// How far below the current price the lowest rung sits, e.g. 10 = 10%.
+    pub max_drop_percent: Decimal,
+    /// Weight capital toward lower rungs instead of splitting it evenly.
+    #[serde(default)]
+    pub weight_lower_rungs: bool,
+}
+
+/// Maintains `grid_count` evenly (linearly) spaced price levels between
+/// `lower` and `upper` and keeps one basket's worth of capital ready at each,
+/// harvesting oscillations by buying dips through a level and selling rallies
+/// through the next level up — independent of the dip-buying/ladder engine
+/// above, and of net trend.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GridConfig {
+    /// Number of price levels; capital is split `fiat_balance / grid_count` per level.
+    pub grid_count: u32,
+    /// Lower bound of the grid. Falls back to the asset's tracked recent low if unset.
+    #[serde(default)]
+    pub lower: Option<Decimal>,
+    /// Upper bound of the grid. Falls back to the asset's tracked recent high if unset.
+    #[serde(default)]
+    pub upper: Option<Decimal>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -35,6 +166,37 @@ pub struct AssetConfig {
     pub initial_crypto_amount: Decimal,
     pub fiat_symbol: String,
     pub crypto_symbol: String,
+    /// Optional multi-asset target-weight rebalancing layered on top of the
+    /// single-asset dip-buying/basket engine above.
+    #[serde(default)]
+    pub portfolio: Option<PortfolioConfig>,
+}
+
+/// Steers `BotState`'s crypto balances toward configured target weights via
+/// [`crate::state::BotState::rebalance`], independent of the basket engine.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PortfolioConfig {
+    pub targets: Vec<AssetTarget>,
+    /// Fiat kept aside and never allocated to an asset.
+    #[serde(default)]
+    pub min_cash_reserve: Decimal,
+    /// Skip a rebalance trade smaller than this many fiat units, to avoid dust orders.
+    pub min_trade_volume: Decimal,
+    /// Rebalance every N bot cycles, instead of on every single one.
+    pub rebalance_every_cycles: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AssetTarget {
+    pub symbol: String,
+    /// Target share of total net portfolio value, e.g. 40 = 40%.
+    pub target_weight_percent: Decimal,
+    /// Hard floor on this asset's value; the top-down pass won't allocate less.
+    #[serde(default)]
+    pub min_value: Option<Decimal>,
+    /// Hard ceiling on this asset's value; the top-down pass redistributes the excess to other assets.
+    #[serde(default)]
+    pub max_value: Option<Decimal>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures_util::Stream;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::pin::Pin;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Price {
@@ -10,6 +12,9 @@ pub struct Price {
     pub timestamp: DateTime<Utc>,
 }
 
+/// A boxed stream of live ticker updates, as produced by [`ExchangeClient::subscribe_prices`].
+pub type PriceStream = Pin<Box<dyn Stream<Item = Price> + Send>>;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderResult {
     pub order_id: String,
@@ -21,18 +26,82 @@ pub struct OrderResult {
     pub timestamp: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OrderSide {
     Buy,
     Sell,
 }
 
+/// Conditional order semantics beyond a plain market fill, as offered by
+/// mature exchange SDKs. The bot currently evaluates its own exit policies
+/// (stop-loss, trailing stop) by polling prices and calling [`ExchangeClient::sell`]
+/// / [`ExchangeClient::close_position`] directly; [`ExchangeClient::place_conditional_order`]
+/// exists for exchanges that can track the trigger themselves instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderType {
+    /// Trigger a market order once price crosses `trigger_price`.
+    Stop,
+    /// Trigger a limit order once price crosses `trigger_price`.
+    LimitIfTouched,
+    /// Exchange-side trailing stop: trigger once price retraces `trail_percent` from its peak/trough.
+    TrailingStop { trail_percent: Decimal },
+}
+
+/// A margin/swap position as reported by [`ExchangeClient::get_position`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionInfo {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: Decimal,
+    pub entry_price: Decimal,
+    pub leverage: Decimal,
+}
+
 #[async_trait]
 pub trait ExchangeClient: Send + Sync {
+    /// Poll a one-shot snapshot of `symbols`' current prices. The primary way
+    /// to get prices for exchanges without a socket feed; callers that can,
+    /// should prefer [`ExchangeClient::subscribe_prices`] instead.
     async fn get_prices(&self, symbols: &[String]) -> anyhow::Result<Vec<Price>>;
     async fn buy(&self, symbol: &str, amount: Decimal) -> anyhow::Result<OrderResult>;
     async fn sell(&self, symbol: &str, quantity: Decimal) -> anyhow::Result<OrderResult>;
-    // async fn get_balance(&self, asset: &str) -> anyhow::Result<Decimal>;
+    async fn get_balance(&self, asset: &str) -> anyhow::Result<Decimal>;
+
+    /// Subscribe to a live ticker feed for `symbols`, instead of polling [`get_prices`].
+    ///
+    /// Implementations that back onto a WebSocket feed should reconnect and
+    /// re-subscribe transparently on disconnect so callers can treat the
+    /// returned stream as a long-lived source of ticks.
+    async fn subscribe_prices(&self, symbols: &[String]) -> anyhow::Result<PriceStream>;
+
+    /// Open a margin/swap position in `side`'s direction (`Buy` for long,
+    /// `Sell` for short) using `margin` fiat at `leverage`x notional exposure.
+    async fn open_position(
+        &self,
+        symbol: &str,
+        side: OrderSide,
+        margin: Decimal,
+        leverage: Decimal,
+    ) -> anyhow::Result<OrderResult>;
+
+    /// Close `quantity` of an open position. `side` is the side that closes
+    /// it, i.e. the opposite of the side it was opened with.
+    async fn close_position(&self, symbol: &str, side: OrderSide, quantity: Decimal) -> anyhow::Result<OrderResult>;
+
+    /// Query the exchange's view of an open margin/swap position, if any.
+    async fn get_position(&self, symbol: &str) -> anyhow::Result<Option<PositionInfo>>;
+
+    /// Place a conditional order (`order_type`) that the exchange itself
+    /// monitors and fills once `trigger_price` (or, for [`OrderType::TrailingStop`],
+    /// its trailing offset) is reached.
+    async fn place_conditional_order(
+        &self,
+        symbol: &str,
+        order_type: OrderType,
+        side: OrderSide,
+        quantity: Decimal,
+        trigger_price: Decimal,
+    ) -> anyhow::Result<OrderResult>;
 }
 
 pub mod mock;
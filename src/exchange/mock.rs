@@ -1,4 +1,4 @@
-use crate::exchange::{ExchangeClient, Price, OrderResult, OrderSide};
+use crate::exchange::{ExchangeClient, Price, OrderResult, OrderSide, OrderType, PositionInfo};
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 use chrono::{DateTime, Utc};
@@ -17,6 +17,9 @@ pub struct MockClient {
     historical_data: Vec<HistoricalData>,
     current_index: Arc<Mutex<usize>>,
     balances: Arc<Mutex<HashMap<String, Decimal>>>,
+    spread_percent: Decimal,
+    taker_fee_percent: Decimal,
+    maker_fee_percent: Decimal,
 }
 
 impl MockClient {
@@ -25,9 +28,38 @@ impl MockClient {
             historical_data,
             current_index: Arc::new(Mutex::new(0)),
             balances: Arc::new(Mutex::new(initial_balances)),
+            spread_percent: Decimal::ZERO,
+            taker_fee_percent: Decimal::new(1, 1), // 0.1%, the crate's original flat fee
+            maker_fee_percent: Decimal::new(1, 1), // 0.1%, same default as taker until configured
         }
     }
 
+    /// Apply a bid/ask spread and maker/taker fee rates to subsequent fills,
+    /// instead of the zero-spread/0.1%-fee defaults.
+    pub fn with_execution_costs(mut self, spread_percent: Decimal, taker_fee_percent: Decimal, maker_fee_percent: Decimal) -> Self {
+        self.spread_percent = spread_percent;
+        self.taker_fee_percent = taker_fee_percent;
+        self.maker_fee_percent = maker_fee_percent;
+        self
+    }
+
+    /// Mid price adjusted for half the configured spread: above mid for buys, below for sells.
+    fn fill_price(&self, mid_price: Decimal, side: OrderSide) -> Decimal {
+        let half_spread = self.spread_percent / Decimal::from(2);
+        match side {
+            OrderSide::Buy => mid_price * (Decimal::from(100) + half_spread) / Decimal::from(100),
+            OrderSide::Sell => mid_price * (Decimal::from(100) - half_spread) / Decimal::from(100),
+        }
+    }
+
+    fn taker_fee(&self, notional: Decimal) -> Decimal {
+        notional * self.taker_fee_percent / Decimal::from(100)
+    }
+
+    fn maker_fee(&self, notional: Decimal) -> Decimal {
+        notional * self.maker_fee_percent / Decimal::from(100)
+    }
+
     pub fn advance_time(&self) {
         let mut index = self.current_index.lock().unwrap();
         if *index < self.historical_data.len() - 1 {
@@ -79,12 +111,13 @@ impl ExchangeClient for MockClient {
 
     async fn buy(&self, symbol: &str, amount: Decimal) -> anyhow::Result<OrderResult> {
         let prices = self.get_prices(&[symbol.to_string()]).await?;
-        let price = prices.first()
+        let mid_price = prices.first()
             .ok_or_else(|| anyhow::anyhow!("Price not found for {}", symbol))?
             .price;
 
+        let price = self.fill_price(mid_price, OrderSide::Buy);
         let quantity = amount / price;
-        let fee = amount * Decimal::new(1, 3); // 0.1% fee
+        let fee = self.taker_fee(amount);
 
         // Update balances
         {
@@ -109,12 +142,13 @@ impl ExchangeClient for MockClient {
 
     async fn sell(&self, symbol: &str, quantity: Decimal) -> anyhow::Result<OrderResult> {
         let prices = self.get_prices(&[symbol.to_string()]).await?;
-        let price = prices.first()
+        let mid_price = prices.first()
             .ok_or_else(|| anyhow::anyhow!("Price not found for {}", symbol))?
             .price;
 
+        let price = self.fill_price(mid_price, OrderSide::Sell);
         let amount = quantity * price;
-        let fee = amount * Decimal::new(1, 3); // 0.1% fee
+        let fee = self.taker_fee(amount);
 
         // Update balances
         {
@@ -141,4 +175,136 @@ impl ExchangeClient for MockClient {
         let balances = self.balances.lock().unwrap();
         Ok(balances.get(asset).copied().unwrap_or(Decimal::ZERO))
     }
+
+    async fn subscribe_prices(&self, symbols: &[String]) -> anyhow::Result<crate::exchange::PriceStream> {
+        // There is no live feed to subscribe to against historical data, so just
+        // replay the current snapshot once. This keeps the trait implementable
+        // for backtests without pretending to stream ticks that don't exist.
+        let prices = self.get_prices(symbols).await?;
+        Ok(Box::pin(futures_util::stream::iter(prices)))
+    }
+
+    async fn open_position(
+        &self,
+        symbol: &str,
+        side: OrderSide,
+        margin: Decimal,
+        leverage: Decimal,
+    ) -> anyhow::Result<OrderResult> {
+        let prices = self.get_prices(&[symbol.to_string()]).await?;
+        let mid_price = prices.first()
+            .ok_or_else(|| anyhow::anyhow!("Price not found for {}", symbol))?
+            .price;
+
+        let price = self.fill_price(mid_price, side);
+        let notional = margin * leverage;
+        let quantity = notional / price;
+        let fee = self.taker_fee(notional);
+
+        {
+            let mut balances = self.balances.lock().unwrap();
+            let fiat_balance = balances.entry("USDT".to_string()).or_insert(Decimal::ZERO);
+            *fiat_balance -= margin + fee;
+        }
+
+        Ok(OrderResult {
+            order_id: format!("mock_open_{:?}_{}", side, chrono::Utc::now().timestamp()),
+            symbol: symbol.to_string(),
+            side,
+            quantity,
+            price,
+            fee,
+            timestamp: self.get_current_timestamp(),
+        })
+    }
+
+    async fn close_position(&self, symbol: &str, side: OrderSide, quantity: Decimal) -> anyhow::Result<OrderResult> {
+        let prices = self.get_prices(&[symbol.to_string()]).await?;
+        let mid_price = prices.first()
+            .ok_or_else(|| anyhow::anyhow!("Price not found for {}", symbol))?
+            .price;
+
+        let price = self.fill_price(mid_price, side);
+        let amount = quantity * price;
+        let fee = self.taker_fee(amount);
+
+        {
+            let mut balances = self.balances.lock().unwrap();
+            let fiat_balance = balances.entry("USDT".to_string()).or_insert(Decimal::ZERO);
+            // Same side-awareness as open_position: closing a short buys the
+            // asset back and debits cash, closing a long sells it and credits
+            // cash - `side` here is the cover/close direction, not the
+            // position's original direction.
+            match side {
+                OrderSide::Buy => *fiat_balance -= amount + fee,
+                OrderSide::Sell => *fiat_balance += amount - fee,
+            }
+        }
+
+        Ok(OrderResult {
+            order_id: format!("mock_close_{:?}_{}", side, chrono::Utc::now().timestamp()),
+            symbol: symbol.to_string(),
+            side,
+            quantity,
+            price,
+            fee,
+            timestamp: self.get_current_timestamp(),
+        })
+    }
+
+    async fn get_position(&self, _symbol: &str) -> anyhow::Result<Option<PositionInfo>> {
+        // The mock doesn't track margin positions separately from spot
+        // balances; callers rely on BotState's active baskets instead.
+        Ok(None)
+    }
+
+    async fn place_conditional_order(
+        &self,
+        symbol: &str,
+        _order_type: OrderType,
+        side: OrderSide,
+        quantity: Decimal,
+        _trigger_price: Decimal,
+    ) -> anyhow::Result<OrderResult> {
+        // The mock has no order book to hold a conditional order against -
+        // BotState/Basket already evaluate the trigger each cycle, so by the
+        // time this is called the condition has been met and it's just a fill.
+        // Unlike `buy`/`sell`, the fill is charged as a resting order would be:
+        // the maker rate, at the mid price instead of `fill_price`.
+        let mid_price = self
+            .get_prices(&[symbol.to_string()])
+            .await?
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("Price not found for {}", symbol))?
+            .price;
+
+        let amount = quantity * mid_price;
+        let fee = self.maker_fee(amount);
+
+        {
+            let mut balances = self.balances.lock().unwrap();
+            let fiat_balance = balances.entry("USDT".to_string()).or_insert(Decimal::ZERO);
+            let crypto_delta = match side {
+                OrderSide::Buy => {
+                    *fiat_balance -= amount + fee;
+                    quantity
+                }
+                OrderSide::Sell => {
+                    *fiat_balance += amount - fee;
+                    -quantity
+                }
+            };
+            *balances.entry(symbol.to_string()).or_insert(Decimal::ZERO) += crypto_delta;
+        }
+
+        Ok(OrderResult {
+            order_id: format!("mock_conditional_{:?}_{}", side, chrono::Utc::now().timestamp()),
+            symbol: symbol.to_string(),
+            side,
+            quantity,
+            price: mid_price,
+            fee,
+            timestamp: self.get_current_timestamp(),
+        })
+    }
 }
\ No newline at end of file
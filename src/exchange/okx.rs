@@ -1,7 +1,36 @@
-use crate::exchange::{ExchangeClient, Price, OrderResult, OrderSide};
+use crate::exchange::{ExchangeClient, Price, OrderResult, OrderSide, OrderType, PositionInfo, PriceStream};
 use async_trait::async_trait;
+use futures_util::{SinkExt, StreamExt};
 use rust_decimal::Decimal;
 use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use tokio_tungstenite::tungstenite::Message;
+
+const PUBLIC_WS_URL: &str = "wss://ws.okx.com:8443/ws/v5/public";
+
+/// An OKX `tickers` channel frame. OKX interleaves subscription/status frames
+/// with actual ticker data on the same socket, so this is untagged and we
+/// dispatch on which variant successfully deserializes.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum TickerFrame {
+    Data { arg: ChannelArg, data: Vec<TickerPayload> },
+    Event { event: String, #[serde(default)] arg: Option<ChannelArg>, #[serde(default)] msg: Option<String> },
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelArg {
+    #[serde(rename = "instId")]
+    inst_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TickerPayload {
+    #[serde(rename = "instId")]
+    inst_id: String,
+    last: Decimal,
+    ts: String,
+}
 
 pub struct OkxClient {
     api_key: String,
@@ -60,4 +89,132 @@ impl ExchangeClient for OkxClient {
         // Placeholder implementation - would need actual OKX API integration
         todo!("Implement OKX balance query")
     }
+
+    async fn open_position(
+        &self,
+        _symbol: &str,
+        _side: OrderSide,
+        _margin: Decimal,
+        _leverage: Decimal,
+    ) -> anyhow::Result<OrderResult> {
+        // Placeholder implementation - would route to OKX's margin/swap order endpoint
+        todo!("Implement OKX margin/swap position open")
+    }
+
+    async fn close_position(&self, _symbol: &str, _side: OrderSide, _quantity: Decimal) -> anyhow::Result<OrderResult> {
+        // Placeholder implementation - would route to OKX's margin/swap order endpoint
+        todo!("Implement OKX margin/swap position close")
+    }
+
+    async fn get_position(&self, _symbol: &str) -> anyhow::Result<Option<PositionInfo>> {
+        // Placeholder implementation - would query OKX's margin/swap position endpoint
+        todo!("Implement OKX margin/swap position query")
+    }
+
+    async fn place_conditional_order(
+        &self,
+        _symbol: &str,
+        _order_type: OrderType,
+        _side: OrderSide,
+        _quantity: Decimal,
+        _trigger_price: Decimal,
+    ) -> anyhow::Result<OrderResult> {
+        // Placeholder implementation - would route to OKX's algo order endpoint
+        // (`POST /api/v5/trade/order-algo`), which natively supports conditional,
+        // trigger, and trailing-stop order types.
+        todo!("Implement OKX conditional/trailing-stop order placement")
+    }
+
+    async fn subscribe_prices(&self, symbols: &[String]) -> anyhow::Result<PriceStream> {
+        let symbols = symbols.to_vec();
+        let (tx, rx) = tokio::sync::mpsc::channel(256);
+
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = Self::run_ticker_stream(&symbols, &tx).await {
+                    eprintln!("OKX ticker stream error, reconnecting: {e}");
+                }
+                if tx.is_closed() {
+                    break;
+                }
+                tokio::time::sleep(std::time::Duration::from_secs(3)).await;
+            }
+        });
+
+        Ok(Box::pin(tokio_stream::wrappers::ReceiverStream::new(rx)))
+    }
+}
+
+impl OkxClient {
+    async fn run_ticker_stream(
+        symbols: &[String],
+        tx: &tokio::sync::mpsc::Sender<Price>,
+    ) -> anyhow::Result<()> {
+        let (ws_stream, _) = tokio_tungstenite::connect_async(PUBLIC_WS_URL).await?;
+        let (mut write, mut read) = ws_stream.split();
+
+        let args: Vec<_> = symbols
+            .iter()
+            .map(|s| serde_json::json!({"channel": "tickers", "instId": s}))
+            .collect();
+        let subscribe = serde_json::json!({"op": "subscribe", "args": args});
+        write.send(Message::Text(subscribe.to_string())).await?;
+
+        // OKX drops the connection if it sees no traffic for 30s, so send a
+        // plain-text "ping" every 20s, inside that window, and expect "pong" back (handled
+        // below as an unrecognized-frame no-op, same as any other non-JSON text).
+        let mut keepalive = tokio::time::interval(std::time::Duration::from_secs(20));
+        keepalive.tick().await; // first tick fires immediately; consume it
+
+        loop {
+            tokio::select! {
+                _ = keepalive.tick() => {
+                    write.send(Message::Text("ping".to_string())).await?;
+                }
+                msg = read.next() => {
+                    let Some(msg) = msg else {
+                        return Err(anyhow::anyhow!("OKX websocket connection closed"));
+                    };
+                    let msg = msg?;
+                    let Message::Text(text) = msg else { continue };
+
+                    match serde_json::from_str::<TickerFrame>(&text) {
+                        Ok(TickerFrame::Event { event, arg, msg }) => {
+                            if event == "error" {
+                                return Err(anyhow::anyhow!(
+                                    "OKX subscription error: {}",
+                                    msg.unwrap_or_default()
+                                ));
+                            }
+                            println!(
+                                "OKX ws {event}: {}",
+                                arg.map(|a| a.inst_id).unwrap_or_default()
+                            );
+                        }
+                        Ok(TickerFrame::Data { data, .. }) => {
+                            for tick in data {
+                                let timestamp = tick
+                                    .ts
+                                    .parse::<i64>()
+                                    .ok()
+                                    .and_then(|ms| DateTime::from_timestamp_millis(ms))
+                                    .unwrap_or_else(Utc::now);
+
+                                let price = Price {
+                                    symbol: tick.inst_id,
+                                    price: tick.last,
+                                    timestamp,
+                                };
+
+                                if tx.send(price).await.is_err() {
+                                    return Ok(()); // receiver dropped, stop streaming
+                                }
+                            }
+                        }
+                        Err(_) => continue, // ignore frames we don't recognize (e.g. "pong")
+                    }
+                }
+            }
+        }
+    }
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use crate::market_data::{is_cache_fresh, meta_path, CacheMeta};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FearGreedIndex {
@@ -80,6 +81,32 @@ impl FearGreedClient {
 
         Ok(indices)
     }
+
+    /// Fetch `days` of history and write it to the `backtest-data/fear_greed_{days}d.json`
+    /// file `Backtester::load_historical_data` already reads, unless a fresh-enough
+    /// cache exists. Shares [`crate::market_data::fetch_and_cache_prices`]'s
+    /// `.meta.json` staleness check so both fetches are re-fetched on the same
+    /// cadence, even though the Fear & Greed index isn't keyed by
+    /// symbol and so isn't itself a [`crate::market_data::MarketDataProvider`].
+    pub async fn fetch_and_cache(&self, days: u32, cache_expire_seconds: i64) -> anyhow::Result<String> {
+        let data_path = format!("backtest-data/fear_greed_{days}d.json");
+
+        if is_cache_fresh(&data_path, cache_expire_seconds) {
+            println!("Using cached Fear & Greed data at {data_path}");
+            return Ok(data_path);
+        }
+
+        let indices = self.get_historical_index(days).await?;
+        std::fs::create_dir_all("backtest-data")?;
+        std::fs::write(&data_path, serde_json::to_string_pretty(&indices)?)?;
+        std::fs::write(
+            meta_path(&data_path),
+            serde_json::to_string_pretty(&CacheMeta { fetched_at: Utc::now() })?,
+        )?;
+
+        println!("Fetched {} Fear & Greed data points into {data_path}", indices.len());
+        Ok(data_path)
+    }
 }
 
 impl Default for FearGreedClient {
@@ -4,6 +4,9 @@ mod bot;
 mod config;
 mod exchange;
 mod fear_greed;
+mod market_data;
+mod notify;
+mod optimize;
 mod state;
 
 use backtest::Backtester;
@@ -31,6 +34,8 @@ struct Cli {
 enum Commands {
     /// Run the trading bot
     Run,
+    /// Run the trading bot against a live WebSocket ticker feed instead of polling
+    Stream,
     /// Run backtesting
     Backtest {
         /// Number of days to backtest (30, 90, or 180)
@@ -43,6 +48,27 @@ enum Commands {
         #[arg(short, long, default_value = "180")]
         days: u32,
     },
+    /// Tune TradingConfig parameters against backtest performance
+    Optimize {
+        /// Number of days of historical data to backtest against
+        #[arg(short, long)]
+        days: u32,
+        /// Number of random samples used to warm-start the surrogate model
+        #[arg(long, default_value = "10")]
+        random_samples: usize,
+        /// Number of Bayesian optimization iterations after the warm start
+        #[arg(long, default_value = "30")]
+        iterations: usize,
+    },
+    /// Exhaustively sweep a small grid of TradingConfig parameters and rank the results
+    Sweep {
+        /// Number of days of historical data to backtest against
+        #[arg(short, long)]
+        days: u32,
+        /// Ranking criterion: "total-return" or "sharpe"
+        #[arg(long, default_value = "total-return")]
+        objective: String,
+    },
 }
 
 #[tokio::main]
@@ -56,13 +82,76 @@ async fn main() -> anyhow::Result<()> {
         Commands::Run => {
             run_trading_bot(config).await?;
         }
+        Commands::Stream => {
+            run_trading_stream(config).await?;
+        }
         Commands::Backtest { days } => {
             run_backtest(config, days).await?;
         }
         Commands::FetchData { days } => {
-            fetch_historical_data(days).await?;
+            fetch_historical_data(config, days).await?;
+        }
+        Commands::Optimize { days, random_samples, iterations } => {
+            run_optimize(config, days, random_samples, iterations).await?;
         }
+        Commands::Sweep { days, objective } => {
+            run_sweep(config, days, &objective).await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn run_optimize(config: Config, days: u32, random_samples: usize, iterations: usize) -> anyhow::Result<()> {
+    println!("Optimizing trading parameters over {days} days ({random_samples} random samples, {iterations} iterations)...");
+    let report = optimize::optimize(config, days, random_samples, iterations).await?;
+    optimize::print_report(&report, &optimize::default_bounds());
+    Ok(())
+}
+
+async fn run_sweep(config: Config, days: u32, objective: &str) -> anyhow::Result<()> {
+    use backtest::{OptimizeGrid, OptimizeObjective};
+    use rust_decimal::Decimal;
+
+    let objective = match objective {
+        "sharpe" => OptimizeObjective::SharpeRatio,
+        "total-return" => OptimizeObjective::TotalReturnPercent,
+        other => return Err(anyhow::anyhow!("unknown objective '{other}', expected 'total-return' or 'sharpe'")),
+    };
+
+    let grid = OptimizeGrid {
+        basket_count: vec![3, 5, 8],
+        profit_threshold_percent: vec![Decimal::from(5), Decimal::from(10), Decimal::from(15)],
+        min_investment_percent: vec![Decimal::from(5), Decimal::from(10)],
+        max_investment_percent: vec![Decimal::from(20), Decimal::from(30)],
+        fear_greed_threshold: vec![20, 30, 40],
+    };
+    let combinations = grid.basket_count.len()
+        * grid.profit_threshold_percent.len()
+        * grid.min_investment_percent.len()
+        * grid.max_investment_percent.len()
+        * grid.fear_greed_threshold.len();
+    println!("Sweeping {combinations} parameter combinations over {days} days...");
+
+    let mut backtester = Backtester::new(config);
+    backtester.load_historical_data(days)?;
+
+    let results = backtester.optimize(days, &grid, objective).await?;
+    backtester.save_optimize_results(days, &results)?;
+
+    println!("\n=== SWEEP RESULTS (top 5) ===");
+    for ranked in results.iter().take(5) {
+        println!(
+            "score={:.4} basket_count={} profit_threshold={}% min_invest={}% max_invest={}% fear_greed={}",
+            ranked.objective_score,
+            ranked.config_used.basket_count,
+            ranked.config_used.profit_threshold_percent,
+            ranked.config_used.min_investment_percent,
+            ranked.config_used.max_investment_percent,
+            ranked.config_used.fear_greed_threshold,
+        );
     }
+    println!("==============================");
 
     Ok(())
 }
@@ -103,7 +192,18 @@ async fn run_trading_bot(config: Config) -> anyhow::Result<()> {
         config.assets.initial_crypto_amount,
     );
 
-    let mock_client = MockClient::new(Vec::new(), initial_balances);
+    let taker_fee_percent = config
+        .trading
+        .fee_schedule
+        .map(|f| f.taker_percent)
+        .unwrap_or(rust_decimal::Decimal::new(1, 1));
+    let maker_fee_percent = config
+        .trading
+        .fee_schedule
+        .map(|f| f.maker_percent)
+        .unwrap_or(rust_decimal::Decimal::new(1, 1));
+    let mock_client = MockClient::new(Vec::new(), initial_balances)
+        .with_execution_costs(config.trading.spread_percent, taker_fee_percent, maker_fee_percent);
     let exchange = Arc::new(Mutex::new(mock_client));
 
     // Create and run bot
@@ -113,6 +213,35 @@ async fn run_trading_bot(config: Config) -> anyhow::Result<()> {
     Ok(())
 }
 
+async fn run_trading_stream(config: Config) -> anyhow::Result<()> {
+    use exchange::okx::OkxClient;
+
+    println!("Starting Binstra Trading Bot in streaming mode...");
+
+    let state = if std::path::Path::new(&config.state_file).exists() {
+        BotState::load_from_file(&config.state_file)?
+    } else {
+        BotState::new(
+            config.assets.initial_fiat_amount,
+            config.assets.crypto_symbol.clone(),
+            config.assets.initial_crypto_amount,
+        )
+    };
+
+    let okx_client = OkxClient::new(
+        config.exchange.api_key.clone().unwrap_or_default(),
+        config.exchange.api_secret.clone().unwrap_or_default(),
+        config.exchange.passphrase.clone().unwrap_or_default(),
+        config.exchange.sandbox,
+    );
+    let exchange = Arc::new(Mutex::new(okx_client));
+
+    let mut bot = TradingBot::new(config, exchange, state);
+    bot.run_stream().await?;
+
+    Ok(())
+}
+
 async fn run_backtest(config: Config, days: u32) -> anyhow::Result<()> {
     println!("Running backtest for {days} days...");
 
@@ -146,14 +275,71 @@ async fn run_backtest(config: Config, days: u32) -> anyhow::Result<()> {
         "Max Drawdown: ${} ({:.2}%)",
         result.max_drawdown, result.max_drawdown_percent
     );
+    println!("Sharpe Ratio: {:.2}", result.sharpe_ratio);
+    println!("Sortino Ratio: {:.2}", result.sortino_ratio);
+    println!("CAGR: {:.2}%", result.cagr_percent);
+    println!("Calmar Ratio: {:.2}", result.calmar_ratio);
+    println!("Profit Factor: {:.2}", result.profit_factor);
+    println!(
+        "Execution Costs: {:.2}% spread, {:.2}% fee",
+        result.effective_spread_percent, result.effective_fee_percent
+    );
+    if !result.grid_level_fills.is_empty() {
+        let mut levels: Vec<_> = result.grid_level_fills.iter().collect();
+        levels.sort_by_key(|(level, _)| **level);
+        for (level, fills) in levels {
+            println!("Grid Level {level} Fills: {fills}");
+        }
+    }
     println!("========================");
 
     Ok(())
 }
 
-async fn fetch_historical_data(days: u32) -> anyhow::Result<()> {
+async fn fetch_historical_data(config: Config, days: u32) -> anyhow::Result<()> {
     println!("Fetching historical data for {days} days...");
-    println!("Please run the Python script manually:");
-    println!("cd backtest-scripts && python3 fetch_historical_data.py --days {days}");
+
+    let Some(market_data_config) = config.market_data.clone() else {
+        println!("No [market_data] section configured; falling back to the manual fetch script:");
+        println!("cd backtest-scripts && python3 fetch_historical_data.py --days {days}");
+        return Ok(());
+    };
+
+    let provider: Box<dyn market_data::MarketDataProvider> = match market_data_config.provider {
+        config::MarketDataProviderKind::Http => {
+            let url_template = market_data_config
+                .url_template
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("market_data.url_template is required for the http provider"))?;
+            Box::new(market_data::HttpProvider::new(url_template))
+        }
+        config::MarketDataProviderKind::CoinMarketCap => {
+            let api_key = market_data_config
+                .api_key
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("market_data.api_key is required for the coinmarketcap provider"))?;
+            Box::new(market_data::CoinMarketCapProvider::new(api_key))
+        }
+        config::MarketDataProviderKind::AlphaVantage => {
+            let api_key = market_data_config
+                .api_key
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("market_data.api_key is required for the alphavantage provider"))?;
+            Box::new(market_data::AlphaVantageProvider::new(api_key))
+        }
+    };
+
+    market_data::fetch_and_cache_prices(
+        provider.as_ref(),
+        &config.assets.crypto_symbol,
+        days,
+        market_data_config.cache_expire_seconds,
+    )
+    .await?;
+
+    fear_greed::FearGreedClient::new()
+        .fetch_and_cache(days, market_data_config.cache_expire_seconds)
+        .await?;
+
     Ok(())
 }
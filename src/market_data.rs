@@ -0,0 +1,225 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// One fetched price point: a timestamp and the symbol -> price map at that
+/// time. Shaped like [`crate::exchange::mock::HistoricalData`] so a
+/// provider's output drops straight into the existing `backtest-data` layout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketDataPoint {
+    pub timestamp: DateTime<Utc>,
+    pub prices: HashMap<String, Decimal>,
+}
+
+/// A source of historical price series for backtesting, selected via
+/// `Config::market_data`. Implementations fetch from whatever upstream API
+/// they wrap and normalize the result into [`MarketDataPoint`]s.
+#[async_trait]
+pub trait MarketDataProvider: Send + Sync {
+    async fn fetch(&self, symbol: &str, days: u32) -> anyhow::Result<Vec<MarketDataPoint>>;
+}
+
+/// Generic HTTP provider: GETs `url_template` (`{symbol}`/`{days}` placeholders
+/// substituted in) and expects a JSON array of `{"timestamp": ..., "price": ...}` objects.
+pub struct HttpProvider {
+    url_template: String,
+    client: reqwest::Client,
+}
+
+impl HttpProvider {
+    pub fn new(url_template: String) -> Self {
+        Self {
+            url_template,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl MarketDataProvider for HttpProvider {
+    async fn fetch(&self, symbol: &str, days: u32) -> anyhow::Result<Vec<MarketDataPoint>> {
+        let url = self
+            .url_template
+            .replace("{symbol}", symbol)
+            .replace("{days}", &days.to_string());
+        let response: Vec<serde_json::Value> = self.client.get(&url).send().await?.json().await?;
+
+        let mut points = Vec::new();
+        for item in response {
+            let (Some(timestamp_str), Some(price)) = (item["timestamp"].as_str(), item["price"].as_f64()) else {
+                continue;
+            };
+            let Ok(timestamp) = timestamp_str.parse::<DateTime<Utc>>() else {
+                continue;
+            };
+            let mut prices = HashMap::new();
+            prices.insert(symbol.to_string(), Decimal::from_f64_retain(price).unwrap_or_default());
+            points.push(MarketDataPoint { timestamp, prices });
+        }
+        Ok(points)
+    }
+}
+
+/// CoinMarketCap's historical-quotes endpoint.
+pub struct CoinMarketCapProvider {
+    api_key: String,
+    client: reqwest::Client,
+}
+
+impl CoinMarketCapProvider {
+    pub fn new(api_key: String) -> Self {
+        Self {
+            api_key,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl MarketDataProvider for CoinMarketCapProvider {
+    async fn fetch(&self, symbol: &str, days: u32) -> anyhow::Result<Vec<MarketDataPoint>> {
+        let url = format!(
+            "https://pro-api.coinmarketcap.com/v2/cryptocurrency/quotes/historical?symbol={symbol}&count={days}&interval=daily"
+        );
+        let response: serde_json::Value = self
+            .client
+            .get(&url)
+            .header("X-CMC_PRO_API_KEY", &self.api_key)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        let mut points = Vec::new();
+        if let Some(quotes) = response["data"]["quotes"].as_array() {
+            for quote in quotes {
+                let (Some(timestamp_str), Some(price)) = (
+                    quote["timestamp"].as_str(),
+                    quote["quote"]["USD"]["price"].as_f64(),
+                ) else {
+                    continue;
+                };
+                let Ok(timestamp) = timestamp_str.parse::<DateTime<Utc>>() else {
+                    continue;
+                };
+                let mut prices = HashMap::new();
+                prices.insert(symbol.to_string(), Decimal::from_f64_retain(price).unwrap_or_default());
+                points.push(MarketDataPoint { timestamp, prices });
+            }
+        }
+        Ok(points)
+    }
+}
+
+/// AlphaVantage's `DIGITAL_CURRENCY_DAILY` endpoint.
+pub struct AlphaVantageProvider {
+    api_key: String,
+    client: reqwest::Client,
+}
+
+impl AlphaVantageProvider {
+    pub fn new(api_key: String) -> Self {
+        Self {
+            api_key,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl MarketDataProvider for AlphaVantageProvider {
+    async fn fetch(&self, symbol: &str, days: u32) -> anyhow::Result<Vec<MarketDataPoint>> {
+        let url = format!(
+            "https://www.alphavantage.co/query?function=DIGITAL_CURRENCY_DAILY&symbol={symbol}&market=USD&apikey={}",
+            self.api_key
+        );
+        let response: serde_json::Value = self.client.get(&url).send().await?.json().await?;
+
+        let mut points = Vec::new();
+        if let Some(series) = response["Time Series (Digital Currency Daily)"].as_object() {
+            for (date_str, ohlc) in series.iter().take(days as usize) {
+                let Some(price_str) = ohlc["4a. close (USD)"].as_str().or_else(|| ohlc["4. close"].as_str()) else {
+                    continue;
+                };
+                let Ok(price) = price_str.parse::<f64>() else {
+                    continue;
+                };
+                let Ok(timestamp) = format!("{date_str}T00:00:00Z").parse::<DateTime<Utc>>() else {
+                    continue;
+                };
+                let mut prices = HashMap::new();
+                prices.insert(symbol.to_string(), Decimal::from_f64_retain(price).unwrap_or_default());
+                points.push(MarketDataPoint { timestamp, prices });
+            }
+        }
+        points.sort_by_key(|p| p.timestamp);
+        Ok(points)
+    }
+}
+
+/// Cache metadata stored alongside a provider's output so a re-fetch can be
+/// skipped until `cache_expire_seconds` have elapsed, without touching the
+/// existing `backtest-data/{asset}_prices_{days}d.json` array format that
+/// `Backtester::load_historical_data` already reads.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CacheMeta {
+    pub(crate) fetched_at: DateTime<Utc>,
+}
+
+pub(crate) fn meta_path(data_path: &str) -> String {
+    format!("{data_path}.meta.json")
+}
+
+pub(crate) fn is_cache_fresh(data_path: &str, cache_expire_seconds: i64) -> bool {
+    if !std::path::Path::new(data_path).exists() {
+        return false;
+    }
+    let Ok(content) = std::fs::read_to_string(meta_path(data_path)) else {
+        return false;
+    };
+    let Ok(meta) = serde_json::from_str::<CacheMeta>(&content) else {
+        return false;
+    };
+    (Utc::now() - meta.fetched_at).num_seconds() < cache_expire_seconds
+}
+
+/// Fetch `symbol`'s last `days` of prices from `provider` and write them into
+/// the `backtest-data` layout, unless a fresh-enough cache already exists.
+/// Returns the path the data was read from or written to.
+pub async fn fetch_and_cache_prices(
+    provider: &dyn MarketDataProvider,
+    symbol: &str,
+    days: u32,
+    cache_expire_seconds: i64,
+) -> anyhow::Result<String> {
+    let data_path = format!("backtest-data/{}_prices_{}d.json", symbol.to_lowercase(), days);
+
+    if is_cache_fresh(&data_path, cache_expire_seconds) {
+        println!("Using cached price data at {data_path}");
+        return Ok(data_path);
+    }
+
+    let points = provider.fetch(symbol, days).await?;
+    std::fs::create_dir_all("backtest-data")?;
+    std::fs::write(&data_path, serde_json::to_string_pretty(&points)?)?;
+    std::fs::write(
+        meta_path(&data_path),
+        serde_json::to_string_pretty(&CacheMeta { fetched_at: Utc::now() })?,
+    )?;
+
+    println!("Fetched {} price points for {symbol} into {data_path}", points.len());
+    Ok(data_path)
+}
+
+/// Find the entry in `items` whose timestamp is closest to `timestamp`. Shared
+/// by the Fear & Greed nearest-timestamp lookup so other gap-filled provider
+/// data (fetched on a different cadence than the price series) can reuse it.
+pub fn nearest_by_timestamp<T>(
+    items: &[T],
+    timestamp: DateTime<Utc>,
+    timestamp_of: impl Fn(&T) -> DateTime<Utc>,
+) -> Option<&T> {
+    items.iter().min_by_key(|item| (timestamp_of(item) - timestamp).num_seconds().abs())
+}
@@ -0,0 +1,119 @@
+use crate::config::NotifyConfig;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+
+/// A bot event worth pushing to an external channel. Each variant carries
+/// just enough context to render a human-readable message.
+#[derive(Debug, Clone)]
+pub enum TradeEvent {
+    BuyFilled { symbol: String, quantity: Decimal, price: Decimal },
+    SellFilled { symbol: String, quantity: Decimal, price: Decimal, profit: Decimal, profit_percent: Decimal },
+    SignalTriggered { signal: String, detail: String },
+    CycleStatistics { portfolio_value: Decimal, active_baskets: u32, total_trades: u32, win_rate: f64 },
+}
+
+impl TradeEvent {
+    fn to_message(&self) -> String {
+        match self {
+            TradeEvent::BuyFilled { symbol, quantity, price } => {
+                format!("\u{1F7E2} Bought {quantity} {symbol} @ {price}")
+            }
+            TradeEvent::SellFilled { symbol, quantity, price, profit, profit_percent } => {
+                format!(
+                    "\u{1F534} Sold {quantity} {symbol} @ {price} | P&L: {profit} ({profit_percent:.2}%)"
+                )
+            }
+            TradeEvent::SignalTriggered { signal, detail } => {
+                format!("\u{26A1} Signal: {signal} — {detail}")
+            }
+            TradeEvent::CycleStatistics { portfolio_value, active_baskets, total_trades, win_rate } => {
+                format!(
+                    "\u{1F4CA} Portfolio: {portfolio_value} | Active baskets: {active_baskets} | Trades: {total_trades} | Win rate: {win_rate:.2}%"
+                )
+            }
+        }
+    }
+}
+
+/// A channel that trade and cycle events can be pushed to. Implementations
+/// should be resilient: a failed `send` is logged by the caller and must not
+/// abort the trading cycle.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn send(&self, event: &TradeEvent) -> anyhow::Result<()>;
+}
+
+pub struct TelegramNotifier {
+    client: reqwest::Client,
+    bot_token: String,
+    chat_id: String,
+}
+
+impl TelegramNotifier {
+    pub fn new(bot_token: String, chat_id: String) -> Self {
+        Self { client: reqwest::Client::new(), bot_token, chat_id }
+    }
+}
+
+#[async_trait]
+impl Notifier for TelegramNotifier {
+    async fn send(&self, event: &TradeEvent) -> anyhow::Result<()> {
+        let url = format!("https://api.telegram.org/bot{}/sendMessage", self.bot_token);
+        self.client
+            .post(&url)
+            .json(&serde_json::json!({
+                "chat_id": self.chat_id,
+                "text": event.to_message(),
+            }))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+pub struct DiscordNotifier {
+    client: reqwest::Client,
+    webhook_url: String,
+}
+
+impl DiscordNotifier {
+    pub fn new(webhook_url: String) -> Self {
+        Self { client: reqwest::Client::new(), webhook_url }
+    }
+}
+
+#[async_trait]
+impl Notifier for DiscordNotifier {
+    async fn send(&self, event: &TradeEvent) -> anyhow::Result<()> {
+        self.client
+            .post(&self.webhook_url)
+            .json(&serde_json::json!({ "content": event.to_message() }))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+/// Build every notifier configured under `[notify]` in `Config`.
+pub fn build_notifiers(config: &Option<NotifyConfig>) -> Vec<Box<dyn Notifier>> {
+    let mut notifiers: Vec<Box<dyn Notifier>> = Vec::new();
+
+    let Some(config) = config else {
+        return notifiers;
+    };
+
+    if let Some(telegram) = &config.telegram {
+        notifiers.push(Box::new(TelegramNotifier::new(
+            telegram.bot_token.clone(),
+            telegram.chat_id.clone(),
+        )));
+    }
+
+    if let Some(discord) = &config.discord {
+        notifiers.push(Box::new(DiscordNotifier::new(discord.webhook_url.clone())));
+    }
+
+    notifiers
+}
@@ -0,0 +1,289 @@
+use crate::backtest::Backtester;
+use crate::config::Config;
+use rand::Rng;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+/// Inclusive `[min, max]` bounds for a single tunable `TradingConfig` field.
+#[derive(Debug, Clone)]
+pub struct ParamBound {
+    pub name: &'static str,
+    pub min: f64,
+    pub max: f64,
+}
+
+/// The set of `TradingConfig` fields this search tunes, in a fixed order that
+/// also defines the parameter vector layout used throughout this module.
+pub fn default_bounds() -> Vec<ParamBound> {
+    vec![
+        ParamBound { name: "fear_greed_threshold", min: 10.0, max: 45.0 },
+        ParamBound { name: "buy_the_dip_percent", min: 2.0, max: 20.0 },
+        ParamBound { name: "profit_threshold_percent", min: 2.0, max: 30.0 },
+        ParamBound { name: "min_investment_percent", min: 1.0, max: 10.0 },
+        ParamBound { name: "max_investment_percent", min: 10.0, max: 50.0 },
+        ParamBound { name: "basket_count", min: 1.0, max: 10.0 },
+    ]
+}
+
+fn apply_params(base: &Config, bounds: &[ParamBound], params: &[f64]) -> Config {
+    let mut config = base.clone();
+    for (bound, &value) in bounds.iter().zip(params.iter()) {
+        match bound.name {
+            "fear_greed_threshold" => config.trading.fear_greed_threshold = value.round() as u32,
+            "buy_the_dip_percent" => config.trading.buy_the_dip_percent = Decimal::from_f64_retain(value).unwrap_or_default(),
+            "profit_threshold_percent" => config.trading.profit_threshold_percent = Decimal::from_f64_retain(value).unwrap_or_default(),
+            "min_investment_percent" => config.trading.min_investment_percent = Decimal::from_f64_retain(value).unwrap_or_default(),
+            "max_investment_percent" => config.trading.max_investment_percent = Decimal::from_f64_retain(value).unwrap_or_default(),
+            "basket_count" => config.trading.basket_count = value.round() as u32,
+            other => unreachable!("unknown tunable parameter {other}"),
+        }
+    }
+    config
+}
+
+fn random_vector(bounds: &[ParamBound], rng: &mut impl Rng) -> Vec<f64> {
+    bounds.iter().map(|b| rng.gen_range(b.min..=b.max)).collect()
+}
+
+/// One `(parameter_vector, objective_score)` pair observed so far.
+#[derive(Debug, Clone)]
+struct Observation {
+    params: Vec<f64>,
+    score: f64,
+}
+
+/// A single regression tree trained by recursive variance-reduction splits.
+/// This is the per-tree building block of [`RandomForest`]; it has no
+/// external dependency beyond basic arithmetic.
+enum TreeNode {
+    Leaf { value: f64 },
+    Split { feature: usize, threshold: f64, left: Box<TreeNode>, right: Box<TreeNode> },
+}
+
+struct DecisionTree {
+    root: TreeNode,
+}
+
+impl DecisionTree {
+    fn fit(data: &[(Vec<f64>, f64)], max_depth: usize, min_samples_leaf: usize) -> Self {
+        Self { root: Self::build(data, max_depth, min_samples_leaf) }
+    }
+
+    fn build(data: &[(Vec<f64>, f64)], depth: usize, min_samples_leaf: usize) -> TreeNode {
+        let mean = data.iter().map(|(_, y)| y).sum::<f64>() / data.len() as f64;
+        if depth == 0 || data.len() < 2 * min_samples_leaf {
+            return TreeNode::Leaf { value: mean };
+        }
+
+        let n_features = data[0].0.len();
+        let mut best: Option<(usize, f64, f64)> = None; // (feature, threshold, variance_reduction)
+        let total_variance = variance(data.iter().map(|(_, y)| *y));
+
+        for feature in 0..n_features {
+            let mut values: Vec<f64> = data.iter().map(|(x, _)| x[feature]).collect();
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            values.dedup();
+
+            for window in values.windows(2) {
+                let threshold = (window[0] + window[1]) / 2.0;
+                let (left, right): (Vec<_>, Vec<_>) =
+                    data.iter().partition(|(x, _)| x[feature] <= threshold);
+
+                if left.len() < min_samples_leaf || right.len() < min_samples_leaf {
+                    continue;
+                }
+
+                let weighted_variance = (left.len() as f64 * variance(left.iter().map(|(_, y)| *y))
+                    + right.len() as f64 * variance(right.iter().map(|(_, y)| *y)))
+                    / data.len() as f64;
+                let reduction = total_variance - weighted_variance;
+
+                if best.map(|(_, _, best_reduction)| reduction > best_reduction).unwrap_or(true) {
+                    best = Some((feature, threshold, reduction));
+                }
+            }
+        }
+
+        match best {
+            Some((feature, threshold, reduction)) if reduction > 0.0 => {
+                let (left, right): (Vec<_>, Vec<_>) =
+                    data.iter().cloned().partition(|(x, _)| x[feature] <= threshold);
+                TreeNode::Split {
+                    feature,
+                    threshold,
+                    left: Box::new(Self::build(&left, depth - 1, min_samples_leaf)),
+                    right: Box::new(Self::build(&right, depth - 1, min_samples_leaf)),
+                }
+            }
+            _ => TreeNode::Leaf { value: mean },
+        }
+    }
+
+    fn predict(&self, x: &[f64]) -> f64 {
+        let mut node = &self.root;
+        loop {
+            match node {
+                TreeNode::Leaf { value } => return *value,
+                TreeNode::Split { feature, threshold, left, right } => {
+                    node = if x[*feature] <= *threshold { left } else { right };
+                }
+            }
+        }
+    }
+}
+
+fn variance(values: impl Iterator<Item = f64> + Clone) -> f64 {
+    let n = values.clone().count() as f64;
+    if n == 0.0 {
+        return 0.0;
+    }
+    let mean = values.clone().sum::<f64>() / n;
+    values.map(|v| (v - mean).powi(2)).sum::<f64>() / n
+}
+
+/// A bootstrap-aggregated ensemble of [`DecisionTree`]s used as the surrogate
+/// model for Bayesian optimization: `predict` gives the posterior mean/std
+/// across trees, standing in for a Gaussian process without the dependency.
+struct RandomForest {
+    trees: Vec<DecisionTree>,
+}
+
+impl RandomForest {
+    fn fit(observations: &[Observation], n_trees: usize, rng: &mut impl Rng) -> Self {
+        let data: Vec<(Vec<f64>, f64)> =
+            observations.iter().map(|o| (o.params.clone(), o.score)).collect();
+
+        let trees = (0..n_trees)
+            .map(|_| {
+                let bootstrap: Vec<_> = (0..data.len())
+                    .map(|_| data[rng.gen_range(0..data.len())].clone())
+                    .collect();
+                DecisionTree::fit(&bootstrap, 4, 2)
+            })
+            .collect();
+
+        Self { trees }
+    }
+
+    fn predict(&self, x: &[f64]) -> (f64, f64) {
+        let predictions: Vec<f64> = self.trees.iter().map(|t| t.predict(x)).collect();
+        let mean = predictions.iter().sum::<f64>() / predictions.len() as f64;
+        let std = variance(predictions.into_iter()).sqrt();
+        (mean, std)
+    }
+}
+
+/// Standard normal CDF via the Abramowitz-Stegun erf approximation.
+fn normal_cdf(z: f64) -> f64 {
+    0.5 * (1.0 + erf(z / std::f64::consts::SQRT_2))
+}
+
+fn normal_pdf(z: f64) -> f64 {
+    (-0.5 * z * z).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+fn erf(x: f64) -> f64 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+    let a1 = 0.254829592;
+    let a2 = -0.284496736;
+    let a3 = 1.421413741;
+    let a4 = -1.453152027;
+    let a5 = 1.061405429;
+    let p = 0.3275911;
+
+    let t = 1.0 / (1.0 + p * x);
+    let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-x * x).exp();
+    sign * y
+}
+
+/// Expected Improvement of a candidate over the best score observed so far.
+fn expected_improvement(mean: f64, std: f64, best_score: f64, xi: f64) -> f64 {
+    if std <= 1e-9 {
+        return 0.0;
+    }
+    let z = (mean - best_score - xi) / std;
+    (mean - best_score - xi) * normal_cdf(z) + std * normal_pdf(z)
+}
+
+pub struct OptimizeReport {
+    pub best_params: Vec<f64>,
+    pub best_score: f64,
+    pub evaluations: usize,
+}
+
+/// Sequential model-based optimization of `TradingConfig` against backtest
+/// performance: sample randomly, fit a random-forest surrogate over all
+/// observations so far, then pick the next candidate by maximizing Expected
+/// Improvement over a large pool of random candidates.
+pub async fn optimize(
+    base_config: Config,
+    days: u32,
+    random_samples: usize,
+    iterations: usize,
+) -> anyhow::Result<OptimizeReport> {
+    let bounds = default_bounds();
+    let mut rng = rand::thread_rng();
+    let mut observations = Vec::with_capacity(random_samples + iterations);
+
+    for i in 0..random_samples {
+        let params = random_vector(&bounds, &mut rng);
+        let score = evaluate(&base_config, &bounds, &params, days).await?;
+        println!("[optimize] random sample {}/{random_samples}: score={score:.4}", i + 1);
+        observations.push(Observation { params, score });
+    }
+
+    for i in 0..iterations {
+        let forest = RandomForest::fit(&observations, 25, &mut rng);
+        let best_score = observations.iter().map(|o| o.score).fold(f64::NEG_INFINITY, f64::max);
+
+        let mut best_candidate = None;
+        let mut best_ei = f64::NEG_INFINITY;
+        for _ in 0..2000 {
+            let candidate = random_vector(&bounds, &mut rng);
+            let (mean, std) = forest.predict(&candidate);
+            let ei = expected_improvement(mean, std, best_score, 0.01);
+            if ei > best_ei {
+                best_ei = ei;
+                best_candidate = Some(candidate);
+            }
+        }
+
+        let params = best_candidate.unwrap_or_else(|| random_vector(&bounds, &mut rng));
+        let score = evaluate(&base_config, &bounds, &params, days).await?;
+        println!(
+            "[optimize] iteration {}/{iterations}: EI={best_ei:.6} score={score:.4}",
+            i + 1
+        );
+        observations.push(Observation { params, score });
+    }
+
+    let best = observations
+        .iter()
+        .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap())
+        .expect("at least one evaluation was run");
+
+    Ok(OptimizeReport {
+        best_params: best.params.clone(),
+        best_score: best.score,
+        evaluations: observations.len(),
+    })
+}
+
+async fn evaluate(base_config: &Config, bounds: &[ParamBound], params: &[f64], days: u32) -> anyhow::Result<f64> {
+    let config = apply_params(base_config, bounds, params);
+    let mut backtester = Backtester::new(config);
+    backtester.load_historical_data(days)?;
+    let result = backtester.run_backtest(days).await?;
+    Ok(result.total_return_percent.to_f64().unwrap_or(f64::NEG_INFINITY))
+}
+
+pub fn print_report(report: &OptimizeReport, bounds: &[ParamBound]) {
+    println!("\n=== OPTIMIZE RESULTS ===");
+    println!("Evaluations: {}", report.evaluations);
+    println!("Best total_return_percent: {:.4}%", report.best_score);
+    for (bound, value) in bounds.iter().zip(report.best_params.iter()) {
+        println!("  {} = {:.4}", bound.name, value);
+    }
+    println!("========================");
+}
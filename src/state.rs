@@ -1,4 +1,6 @@
-use crate::basket::Basket;
+use crate::basket::{Basket, ExitReason};
+use crate::config::AssetTarget;
+use crate::exchange::OrderSide;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -14,6 +16,40 @@ pub struct BotState {
     pub total_invested: Decimal,
     pub total_profit: Decimal,
     pub recent_highs: HashMap<String, Decimal>, // Symbol -> Recent high price
+    #[serde(default)]
+    pub recent_lows: HashMap<String, Decimal>, // Symbol -> Recent low price
+    #[serde(default)]
+    pub pending_rungs: Vec<PendingRung>,
+    /// Cycles elapsed since the last portfolio [`Self::rebalance`], so callers
+    /// can enforce `PortfolioConfig::rebalance_every_cycles`.
+    #[serde(default)]
+    pub cycles_since_rebalance: u32,
+    /// Monotonic counter appended to basket ids by [`Self::add_basket`]/[`Self::fill_rung`]
+    /// so baskets opened for the same asset in the same cycle (e.g. several
+    /// grid levels crossed in one tick) don't collide on `asset_timestamp` alone.
+    #[serde(default)]
+    pub next_basket_seq: u64,
+}
+
+/// A trade [`BotState::rebalance`] wants executed to steer a held asset back
+/// toward its target weight.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RebalanceTrade {
+    pub symbol: String,
+    pub side: OrderSide,
+    /// Fiat value to buy (`OrderSide::Buy`) or sell (`OrderSide::Sell`).
+    pub value: Decimal,
+}
+
+/// A ladder rung that is reserved but not yet bought: capital is set aside
+/// and the rung turns into an active [`Basket`] once price drops to its level.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingRung {
+    pub id: String,
+    pub asset: String,
+    pub buy_price_level: Decimal,
+    pub allocated_fiat: Decimal,
+    pub target_profit_percent: Decimal,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +59,7 @@ pub struct ClosedBasket {
     pub sell_timestamp: DateTime<Utc>,
     pub profit: Decimal,
     pub profit_percent: Decimal,
+    pub exit_reason: ExitReason,
 }
 
 impl BotState {
@@ -43,9 +80,21 @@ impl BotState {
             total_invested: Decimal::ZERO,
             total_profit: Decimal::ZERO,
             recent_highs: HashMap::new(),
+            recent_lows: HashMap::new(),
+            pending_rungs: Vec::new(),
+            cycles_since_rebalance: 0,
+            next_basket_seq: 0,
         }
     }
 
+    /// Assign a basket id that's unique within this state even if another
+    /// basket for the same asset was opened at the same `timestamp`.
+    fn next_basket_id(&mut self, asset: &str, timestamp: DateTime<Utc>) -> String {
+        let seq = self.next_basket_seq;
+        self.next_basket_seq += 1;
+        format!("basket_{asset}_{}_{seq}", timestamp.timestamp())
+    }
+
     pub fn save_to_file(&self, file_path: &str) -> anyhow::Result<()> {
         let json = serde_json::to_string_pretty(self)?;
         std::fs::write(file_path, json)?;
@@ -58,7 +107,8 @@ impl BotState {
         Ok(state)
     }
 
-    pub fn add_basket(&mut self, basket: Basket) {
+    pub fn add_basket(&mut self, mut basket: Basket) {
+        basket.id = self.next_basket_id(&basket.asset, basket.buy_timestamp);
         let invested_amount = basket.get_invested_amount();
         self.fiat_balance -= invested_amount;
         self.total_invested += invested_amount;
@@ -66,14 +116,55 @@ impl BotState {
         self.last_update = Utc::now();
     }
 
-    pub fn close_basket(&mut self, basket_id: &str, sell_price: Decimal) -> anyhow::Result<()> {
+    /// Reserve fiat for a set of ladder rungs without buying anything yet.
+    /// The fiat is set aside immediately so concurrent lump-sum buys can't
+    /// double-spend it while the rungs wait to fill.
+    pub fn add_pending_rungs(&mut self, rungs: Vec<PendingRung>) {
+        for rung in &rungs {
+            self.fiat_balance -= rung.allocated_fiat;
+        }
+        self.pending_rungs.extend(rungs);
+        self.last_update = Utc::now();
+    }
+
+    /// Remove and return every pending rung for `asset` whose level has been
+    /// reached by `current_price` (i.e. price has dropped to or below it).
+    pub fn take_triggered_rungs(&mut self, asset: &str, current_price: Decimal) -> Vec<PendingRung> {
+        let (triggered, remaining): (Vec<_>, Vec<_>) = self
+            .pending_rungs
+            .drain(..)
+            .partition(|rung| rung.asset == asset && current_price <= rung.buy_price_level);
+        self.pending_rungs = remaining;
+        triggered
+    }
+
+    /// Activate a [`Basket`] for a rung whose fiat was already reserved by
+    /// [`Self::add_pending_rungs`] — unlike [`Self::add_basket`] this does not
+    /// deduct fiat again.
+    pub fn fill_rung(&mut self, mut basket: Basket) {
+        basket.id = self.next_basket_id(&basket.asset, basket.buy_timestamp);
+        self.total_invested += basket.get_invested_amount();
+        self.active_baskets.push(basket);
+        self.last_update = Utc::now();
+    }
+
+    pub fn close_basket(
+        &mut self,
+        basket_id: &str,
+        sell_price: Decimal,
+        exit_reason: ExitReason,
+        fee: Decimal,
+    ) -> anyhow::Result<()> {
         if let Some(index) = self.active_baskets.iter().position(|b| b.id == basket_id) {
             let basket = self.active_baskets.remove(index);
-            let sell_amount = basket.quantity * sell_price;
             let profit = basket.get_profit(sell_price);
             let profit_percent = basket.get_profit_percent(sell_price);
 
-            self.fiat_balance += sell_amount;
+            // Credit back the margin actually committed plus/minus the
+            // leverage-independent dollar profit, not `quantity * sell_price` -
+            // that's only correct for an unleveraged long and either fabricates
+            // or destroys cash for shorts/leverage>1 (see get_profit/get_invested_amount).
+            self.fiat_balance += basket.get_invested_amount() + profit - fee;
             self.total_profit += profit;
 
             let closed_basket = ClosedBasket {
@@ -82,6 +173,7 @@ impl BotState {
                 sell_timestamp: Utc::now(),
                 profit,
                 profit_percent,
+                exit_reason,
             };
 
             self.closed_baskets.push(closed_basket);
@@ -177,6 +269,152 @@ impl BotState {
         }
         Decimal::ZERO
     }
+
+    pub fn update_recent_low(&mut self, symbol: &str, current_price: Decimal) {
+        let recent_low = self
+            .recent_lows
+            .entry(symbol.to_string())
+            .or_insert(current_price);
+        if current_price < *recent_low {
+            *recent_low = current_price;
+        }
+    }
+
+    /// Mirrors [`Self::is_price_dip`] for the short side: true once price has
+    /// rallied `rally_threshold_percent` or more above the recent low.
+    pub fn is_price_rally(
+        &self,
+        symbol: &str,
+        current_price: Decimal,
+        rally_threshold_percent: Decimal,
+    ) -> bool {
+        if let Some(&recent_low) = self.recent_lows.get(symbol) {
+            if recent_low > Decimal::ZERO {
+                let rally_percent = (current_price - recent_low) / recent_low * Decimal::from(100);
+                return rally_percent >= rally_threshold_percent;
+            }
+        }
+        false
+    }
+
+    pub fn get_rally_percentage(&self, symbol: &str, current_price: Decimal) -> Decimal {
+        if let Some(&recent_low) = self.recent_lows.get(symbol) {
+            if recent_low > Decimal::ZERO {
+                let rally_percent = (current_price - recent_low) / recent_low * Decimal::from(100);
+                return rally_percent.max(Decimal::ZERO);
+            }
+        }
+        Decimal::ZERO
+    }
+
+    /// Compute the trades needed to steer `crypto_balances` toward `targets`'
+    /// weights, using a two-pass bottom-up/top-down allocation: first derive
+    /// each asset's hard min/max value restriction, then distribute investable
+    /// value (`fiat_balance + held asset value - min_cash_reserve`)
+    /// proportional to weight, clamping to each restriction and redistributing
+    /// the clamped remainder across the assets that aren't yet clamped.
+    /// Trades smaller than `min_trade_volume` are dropped to avoid dust orders.
+    pub fn rebalance(
+        &self,
+        current_prices: &HashMap<String, Decimal>,
+        targets: &[AssetTarget],
+        min_cash_reserve: Decimal,
+        min_trade_volume: Decimal,
+    ) -> Vec<RebalanceTrade> {
+        let current_values: HashMap<&str, Decimal> = targets
+            .iter()
+            .map(|t| {
+                let balance = self.crypto_balances.get(&t.symbol).copied().unwrap_or(Decimal::ZERO);
+                let price = current_prices.get(&t.symbol).copied().unwrap_or(Decimal::ZERO);
+                (t.symbol.as_str(), balance * price)
+            })
+            .collect();
+
+        let total_asset_value: Decimal = current_values.values().copied().sum();
+        let total_net_value = self.fiat_balance + total_asset_value;
+        let investable = (total_net_value - min_cash_reserve).max(Decimal::ZERO);
+
+        let restrictions: HashMap<&str, (Decimal, Decimal)> = targets
+            .iter()
+            .map(|t| {
+                let min = t.min_value.unwrap_or(Decimal::ZERO);
+                let max = t.max_value.unwrap_or(total_net_value);
+                (t.symbol.as_str(), (min, max))
+            })
+            .collect();
+
+        // Top-down pass: distribute `investable` proportional to weight,
+        // clamping to each restriction and re-deriving the per-weight share
+        // from whatever's left over each time an asset gets clamped.
+        let mut allocated: HashMap<&str, Decimal> = HashMap::new();
+        let mut pending: Vec<&AssetTarget> = targets.iter().collect();
+        let mut remaining = investable;
+        let mut remaining_weight: Decimal = targets.iter().map(|t| t.target_weight_percent).sum();
+
+        while !pending.is_empty() && remaining_weight > Decimal::ZERO {
+            let share_per_weight = remaining / remaining_weight;
+            let mut next_pending = Vec::new();
+            let mut clamped_any = false;
+
+            for t in &pending {
+                let (min, max) = restrictions[t.symbol.as_str()];
+                let ideal = share_per_weight * t.target_weight_percent;
+                if ideal < min {
+                    allocated.insert(t.symbol.as_str(), min);
+                    remaining -= min;
+                    remaining_weight -= t.target_weight_percent;
+                    clamped_any = true;
+                } else if ideal > max {
+                    allocated.insert(t.symbol.as_str(), max);
+                    remaining -= max;
+                    remaining_weight -= t.target_weight_percent;
+                    clamped_any = true;
+                } else {
+                    next_pending.push(*t);
+                }
+            }
+
+            if !clamped_any {
+                for t in &next_pending {
+                    allocated.insert(t.symbol.as_str(), share_per_weight * t.target_weight_percent);
+                }
+                break;
+            }
+            pending = next_pending;
+        }
+
+        targets
+            .iter()
+            .filter_map(|t| {
+                let target_value = allocated.get(t.symbol.as_str()).copied().unwrap_or(Decimal::ZERO);
+                let current_value = current_values.get(t.symbol.as_str()).copied().unwrap_or(Decimal::ZERO);
+                let delta = target_value - current_value;
+                if delta.abs() < min_trade_volume {
+                    None
+                } else if delta > Decimal::ZERO {
+                    Some(RebalanceTrade { symbol: t.symbol.clone(), side: OrderSide::Buy, value: delta })
+                } else {
+                    Some(RebalanceTrade { symbol: t.symbol.clone(), side: OrderSide::Sell, value: -delta })
+                }
+            })
+            .collect()
+    }
+
+    /// Apply a filled rebalance trade's effect on `fiat_balance`/`crypto_balances`.
+    /// Unlike baskets, rebalanced holdings aren't tracked as a separate position.
+    pub fn apply_rebalance_fill(&mut self, symbol: &str, side: OrderSide, quantity: Decimal, price: Decimal, fee: Decimal) {
+        match side {
+            OrderSide::Buy => {
+                self.fiat_balance -= quantity * price + fee;
+                *self.crypto_balances.entry(symbol.to_string()).or_insert(Decimal::ZERO) += quantity;
+            }
+            OrderSide::Sell => {
+                self.fiat_balance += quantity * price - fee;
+                *self.crypto_balances.entry(symbol.to_string()).or_insert(Decimal::ZERO) -= quantity;
+            }
+        }
+        self.last_update = Utc::now();
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]